@@ -0,0 +1,33 @@
+// Minimal benchmark harness, the crate has no dependencies so there is no criterion. Each
+// benchmark is warmed up, then timed over enough iterations to run for about a second.
+
+#![allow(dead_code)]
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+pub fn bench<T>(name: &str, mut f: impl FnMut() -> T) -> Duration {
+    for _ in 0..10 {
+        black_box(f());
+    }
+
+    let mut iterations = 1_u32;
+    loop {
+        let start = Instant::now();
+        for _ in 0..iterations {
+            black_box(f());
+        }
+        let elapsed = start.elapsed();
+        if elapsed >= Duration::from_secs(1) || iterations >= 1 << 24 {
+            let per_iteration = elapsed / iterations;
+            println!(
+                "{:<40} {:>12?}/iter ({} iterations)",
+                name, per_iteration, iterations
+            );
+            return per_iteration;
+        }
+        iterations *= 2;
+    }
+}
+
+pub use aoc2022::rng::Rng;
@@ -0,0 +1,77 @@
+// Bitmask rucksacks against the previous HashSet implementation (`cargo bench --bench day03`)
+
+mod common;
+
+use std::collections::HashSet;
+
+use aoc2022::testing::day03::{group, rucksack};
+use aoc2022::y2022::day03;
+use common::{bench, Rng};
+
+fn priority(c: char) -> u32 {
+    if c.is_ascii_lowercase() {
+        c as u32 - 'a' as u32 + 1
+    } else {
+        c as u32 - 'A' as u32 + 27
+    }
+}
+
+fn hash_set_part1(lines: &[String]) -> u32 {
+    let mut score = 0;
+    for rucksack in lines {
+        let (h1, h2) = rucksack.split_at(rucksack.len() / 2);
+        let set: HashSet<char> = h1.chars().collect();
+        if let Some(c) = h2.chars().find(|c| set.contains(c)) {
+            score += priority(c);
+        }
+    }
+    score
+}
+
+fn hash_set_part2(lines: &[String]) -> u32 {
+    let mut score = 0;
+    for group in lines.chunks(3) {
+        let s1: HashSet<char> = group[0].chars().collect();
+        let s2: HashSet<char> = group[1].chars().collect();
+        if let Some(c) = s1.intersection(&s2).find(|&&c| group[2].contains(c)) {
+            score += priority(*c);
+        }
+    }
+    score
+}
+
+fn main() {
+    let mut rng = Rng::new(2022);
+    // 300 rucksacks of 48 items, the size of a puzzle input
+    let lines: Vec<String> = (0..300).map(|_| rucksack(&mut rng, 24..=24)).collect();
+    assert_eq!(day03::part1(lines.clone()), Ok(hash_set_part1(&lines)));
+
+    let hash_set = bench("day03 part1 (HashSet)", || hash_set_part1(&lines));
+    let bitmask = bench("day03 part1 (bitmask)", || {
+        day03::parse_rucksacks(&lines)
+            .unwrap()
+            .iter()
+            .filter_map(|r| r.common().first())
+            .sum::<u32>()
+    });
+    println!(
+        "speedup: {:.1}x",
+        hash_set.as_secs_f64() / bitmask.as_secs_f64()
+    );
+
+    // 100 groups of 3 rucksacks of 48 items
+    let lines: Vec<String> = (0..100).flat_map(|_| group(&mut rng, 48..=48)).collect();
+    assert_eq!(day03::part2(lines.clone()), Ok(hash_set_part2(&lines)));
+    let hash_set = bench("day03 part2 (HashSet)", || hash_set_part2(&lines));
+    let bitmask = bench("day03 part2 (bitmask)", || {
+        day03::badges(&lines, 3)
+            .unwrap()
+            .iter()
+            .filter_map(|b| b.first())
+            .sum::<u32>()
+    });
+    println!(
+        "speedup: {:.1}x",
+        hash_set.as_secs_f64() / bitmask.as_secs_f64()
+    );
+}
@@ -0,0 +1,108 @@
+// Crane moves with `split_off`/`extend` against the previous implementation, which inserted
+// every crate (`cargo bench --bench day05`)
+
+mod common;
+
+use aoc2022::y2022::day05;
+use common::{bench, Rng};
+
+// Previous implementation: crates are inserted at the bottom while parsing, and a CrateMover
+// 9001 inserts every crate below the first one it moved
+fn legacy(lines: Vec<String>, batch: bool) -> String {
+    let n = (lines[0].chars().count() + 1) / 4;
+    let mut stacks: Vec<Vec<char>> = vec![Vec::new(); n];
+    let mut lines = lines.iter();
+    for line in lines.by_ref() {
+        if line.trim_start().starts_with('1') {
+            break;
+        }
+        let chars: Vec<char> = line.chars().collect();
+        for (i, item) in chars.chunks(4).enumerate() {
+            if item[1] != ' ' {
+                stacks[i].insert(0, item[1]);
+            }
+        }
+    }
+
+    for line in lines.skip(1) {
+        let words: Vec<usize> = line.split(' ').filter_map(|w| w.parse().ok()).collect();
+        let (quantity, origin, destination) = (words[0], words[1] - 1, words[2] - 1);
+        let item = stacks[origin].pop().unwrap();
+        stacks[destination].push(item);
+        let dest_n = stacks[destination].len();
+        for _ in 1..quantity {
+            let item = stacks[origin].pop().unwrap();
+            if batch {
+                stacks[destination].insert(dest_n - 1, item);
+            } else {
+                stacks[destination].push(item);
+            }
+        }
+    }
+    stacks.iter().map(|s| s.last().unwrap()).collect()
+}
+
+// Puzzle input with 9 stacks holding `crates` crates in total, and `procedures` procedures moving
+// up to `max_quantity` crates each. Every stack keeps at least one crate so the answer exists.
+fn input(rng: &mut Rng, crates: usize, procedures: usize, max_quantity: usize) -> Vec<String> {
+    let mut heights = [crates / 9; 9];
+    let mut lines: Vec<String> = (0..crates / 9)
+        .map(|_| {
+            (0..9)
+                .map(|_| format!("[{}]", (b'A' + rng.below(26) as u8) as char))
+                .collect::<Vec<_>>()
+                .join(" ")
+        })
+        .collect();
+    lines.push(String::from(" 1   2   3   4   5   6   7   8   9"));
+    lines.push(String::new());
+
+    for _ in 0..procedures {
+        let origin = rng.below(9);
+        let destination = (origin + 1 + rng.below(8)) % 9;
+        let quantity = 1 + rng.below(max_quantity.min(heights[origin] - 1).max(1));
+        if quantity >= heights[origin] {
+            continue;
+        }
+        heights[origin] -= quantity;
+        heights[destination] += quantity;
+        lines.push(format!(
+            "move {} from {} to {}",
+            quantity,
+            origin + 1,
+            destination + 1
+        ));
+    }
+    lines
+}
+
+fn main() {
+    let mut rng = Rng::new(2022);
+
+    // Small enough for the quadratic implementation to finish in a few seconds
+    let lines = input(&mut rng, 100_000, 10_000, 1_000);
+    for batch in [false, true] {
+        let part = if batch { day05::part2 } else { day05::part1 };
+        let name = if batch { "part2" } else { "part1" };
+        assert_eq!(part(lines.clone()), Ok(legacy(lines.clone(), batch)));
+
+        let legacy = bench(&format!("day05 {} 1e5 crates (insert)", name), || {
+            legacy(lines.clone(), batch)
+        });
+        let linear = bench(&format!("day05 {} 1e5 crates (split_off)", name), || {
+            part(lines.clone())
+        });
+        println!(
+            "speedup: {:.1}x",
+            legacy.as_secs_f64() / linear.as_secs_f64()
+        );
+    }
+
+    let lines = input(&mut rng, 1_000_000, 100_000, 1_000);
+    bench("day05 part1 1e6 crates (split_off)", || {
+        day05::part1(lines.clone())
+    });
+    bench("day05 part2 1e6 crates (split_off)", || {
+        day05::part2(lines.clone())
+    });
+}
@@ -0,0 +1,43 @@
+/* Written by aoc2022-capi (capi/src/header.rs), do not edit. */
+
+#ifndef AOC_H
+#define AOC_H
+
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+typedef int32_t aoc_error_t;
+
+#define AOC_OK 0
+#define AOC_ERR_NULL_POINTER 1
+#define AOC_ERR_UNKNOWN_PUZZLE 2
+#define AOC_ERR_INVALID_UTF8 3
+#define AOC_ERR_INVALID_INPUT 4
+#define AOC_ERR_BUFFER_TOO_SMALL 5
+#define AOC_ERR_PANIC 6
+
+/* Solves `part` (1 or 2) of `day` of 2022. `*out_len` holds the capacity of
+ * `out_buf` and receives the length of the NUL terminated answer (or error message when
+ * AOC_ERR_INVALID_INPUT is returned, or needed length for AOC_ERR_BUFFER_TOO_SMALL). */
+aoc_error_t aoc_solve(uint8_t day,
+                      uint8_t part,
+                      const uint8_t *input_ptr,
+                      size_t input_len,
+                      char *out_buf,
+                      size_t *out_len);
+
+/* Writes up to `capacity` solved days of 2022 to `out_days` and returns the total number of days. */
+size_t aoc_list_days(uint8_t *out_days, size_t capacity);
+
+/* Static description of an error code. */
+const char *aoc_strerror(int32_t code);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* AOC_H */
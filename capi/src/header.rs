@@ -0,0 +1,76 @@
+// Writes `include/aoc.h`. The error codes are generated from `ErrorCode`, the function
+// declarations are written by hand below and `test_signatures` checks them against the Rust ones.
+// Run `AOC_BLESS=1 cargo test -p aoc2022-capi` to refresh the committed header after a change.
+
+use crate::ErrorCode;
+
+const FUNCTIONS: &str = "\
+/* Solves `part` (1 or 2) of `day` of 2022. `*out_len` holds the capacity of
+ * `out_buf` and receives the length of the NUL terminated answer (or error message when
+ * AOC_ERR_INVALID_INPUT is returned, or needed length for AOC_ERR_BUFFER_TOO_SMALL). */
+aoc_error_t aoc_solve(uint8_t day,
+                      uint8_t part,
+                      const uint8_t *input_ptr,
+                      size_t input_len,
+                      char *out_buf,
+                      size_t *out_len);
+
+/* Writes up to `capacity` solved days of 2022 to `out_days` and returns the total number of days. */
+size_t aoc_list_days(uint8_t *out_days, size_t capacity);
+
+/* Static description of an error code. */
+const char *aoc_strerror(int32_t code);
+";
+
+pub fn generate() -> String {
+    let mut header = String::from(
+        "/* Written by aoc2022-capi (capi/src/header.rs), do not edit. */\n\n\
+         #ifndef AOC_H\n#define AOC_H\n\n\
+         #include <stddef.h>\n#include <stdint.h>\n\n\
+         #ifdef __cplusplus\nextern \"C\" {\n#endif\n\n\
+         typedef int32_t aoc_error_t;\n\n",
+    );
+    for code in ErrorCode::ALL {
+        header.push_str(&format!("#define {} {}\n", code.name(), code as i32));
+    }
+    header.push('\n');
+    header.push_str(FUNCTIONS);
+    header.push_str("\n#ifdef __cplusplus\n}\n#endif\n\n#endif /* AOC_H */\n");
+    header
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ffi::c_char;
+
+    use super::*;
+    use crate::{aoc_list_days, aoc_solve, aoc_strerror};
+
+    // Fails to build when an exported function no longer has the signature declared in FUNCTIONS
+    #[test]
+    fn test_signatures() {
+        let _: unsafe extern "C" fn(
+            u8,
+            u8,
+            *const u8,
+            usize,
+            *mut c_char,
+            *mut usize,
+        ) -> ErrorCode = aoc_solve;
+        let _: unsafe extern "C" fn(*mut u8, usize) -> usize = aoc_list_days;
+        let _: extern "C" fn(i32) -> *const c_char = aoc_strerror;
+    }
+
+    #[test]
+    fn test_header_is_up_to_date() {
+        let path = concat!(env!("CARGO_MANIFEST_DIR"), "/include/aoc.h");
+        if std::env::var_os("AOC_BLESS").is_some() {
+            std::fs::write(path, generate()).unwrap();
+        }
+        let committed = std::fs::read_to_string(path).unwrap_or_default();
+        assert!(
+            committed == generate(),
+            "include/aoc.h is out of date, run `AOC_BLESS=1 cargo test -p aoc2022-capi`"
+        );
+    }
+}
@@ -0,0 +1,216 @@
+// C ABI for the 2022 solvers. Every function is panic-free and reports failures through an error
+// code, the matching C declarations are in `include/aoc.h` (written by `header::generate`).
+
+use std::ffi::c_char;
+use std::panic::{self, AssertUnwindSafe};
+use std::slice;
+
+use aoc2022::registry::{self, Part};
+
+pub mod header;
+
+// The C API is pinned to one year, so that registering a new year doesn't change what an existing
+// call solves
+const YEAR: u16 = 2022;
+
+#[repr(i32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorCode {
+    Ok = 0,
+    NullPointer = 1,
+    UnknownPuzzle = 2,
+    InvalidUtf8 = 3,
+    // The solver rejected the input, the error message is written to the output buffer
+    InvalidInput = 4,
+    BufferTooSmall = 5,
+    Panic = 6,
+}
+
+impl ErrorCode {
+    pub const ALL: [ErrorCode; 7] = [
+        ErrorCode::Ok,
+        ErrorCode::NullPointer,
+        ErrorCode::UnknownPuzzle,
+        ErrorCode::InvalidUtf8,
+        ErrorCode::InvalidInput,
+        ErrorCode::BufferTooSmall,
+        ErrorCode::Panic,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            ErrorCode::Ok => "AOC_OK",
+            ErrorCode::NullPointer => "AOC_ERR_NULL_POINTER",
+            ErrorCode::UnknownPuzzle => "AOC_ERR_UNKNOWN_PUZZLE",
+            ErrorCode::InvalidUtf8 => "AOC_ERR_INVALID_UTF8",
+            ErrorCode::InvalidInput => "AOC_ERR_INVALID_INPUT",
+            ErrorCode::BufferTooSmall => "AOC_ERR_BUFFER_TOO_SMALL",
+            ErrorCode::Panic => "AOC_ERR_PANIC",
+        }
+    }
+
+    // NUL terminated so it can be handed to C as is
+    fn message(self) -> &'static str {
+        match self {
+            ErrorCode::Ok => "no error\0",
+            ErrorCode::NullPointer => "null pointer argument\0",
+            ErrorCode::UnknownPuzzle => "unknown day or part\0",
+            ErrorCode::InvalidUtf8 => "input is not valid UTF-8\0",
+            ErrorCode::InvalidInput => "invalid puzzle input\0",
+            ErrorCode::BufferTooSmall => "output buffer is too small\0",
+            ErrorCode::Panic => "solver panicked\0",
+        }
+    }
+}
+
+// Copies `text` and a NUL terminator to `out_buf`. `*out_len` holds the buffer capacity on input
+// and the text length (without terminator) on output, even if the buffer was too small.
+unsafe fn write_output(text: &str, out_buf: *mut c_char, out_len: *mut usize) -> bool {
+    let capacity = *out_len;
+    *out_len = text.len();
+    if text.len() >= capacity {
+        return false;
+    }
+    let out = slice::from_raw_parts_mut(out_buf as *mut u8, capacity);
+    out[..text.len()].copy_from_slice(text.as_bytes());
+    out[text.len()] = 0;
+    true
+}
+
+/// Solves `part` (1 or 2) of `day` of 2022 on the `input_len` bytes at `input_ptr`.
+///
+/// On success, the answer is written to `out_buf` as a NUL terminated string. When the solver
+/// rejects the input, the error message is written instead and `AOC_ERR_INVALID_INPUT` is
+/// returned. `*out_len` must hold the capacity of `out_buf`, it is set to the length of the
+/// written string, or to the length needed when `AOC_ERR_BUFFER_TOO_SMALL` is returned.
+///
+/// # Safety
+/// `input_ptr` must point to `input_len` readable bytes, `out_len` must be a valid pointer and
+/// `out_buf` must point to `*out_len` writable bytes.
+#[no_mangle]
+pub unsafe extern "C" fn aoc_solve(
+    day: u8,
+    part: u8,
+    input_ptr: *const u8,
+    input_len: usize,
+    out_buf: *mut c_char,
+    out_len: *mut usize,
+) -> ErrorCode {
+    if (input_ptr.is_null() && input_len > 0) || out_buf.is_null() || out_len.is_null() {
+        return ErrorCode::NullPointer;
+    }
+
+    let part = match part {
+        1 => Part::One,
+        2 => Part::Two,
+        _ => return ErrorCode::UnknownPuzzle,
+    };
+    let puzzle = match registry::find(YEAR, day, part, None) {
+        Some(puzzle) => puzzle,
+        None => return ErrorCode::UnknownPuzzle,
+    };
+
+    let input = if input_len == 0 {
+        &[]
+    } else {
+        slice::from_raw_parts(input_ptr, input_len)
+    };
+    let input = match std::str::from_utf8(input) {
+        Ok(input) => input,
+        Err(_) => return ErrorCode::InvalidUtf8,
+    };
+    let lines = input.lines().map(String::from).collect();
+
+    let (text, code) = match panic::catch_unwind(AssertUnwindSafe(|| (puzzle.solve)(lines))) {
+        Ok(Ok(answer)) => (answer, ErrorCode::Ok),
+        Ok(Err(e)) => (e.to_string(), ErrorCode::InvalidInput),
+        Err(_) => return ErrorCode::Panic,
+    };
+    if write_output(&text, out_buf, out_len) {
+        code
+    } else {
+        ErrorCode::BufferTooSmall
+    }
+}
+
+/// Writes the days of 2022 that have a solution to `out_days`, in increasing order,
+/// and returns how many there are. At most `capacity` days are written, call with a capacity of
+/// 0 to only get the count.
+///
+/// # Safety
+/// `out_days` must point to `capacity` writable bytes (or be null if `capacity` is 0).
+#[no_mangle]
+pub unsafe extern "C" fn aoc_list_days(out_days: *mut u8, capacity: usize) -> usize {
+    let mut days: Vec<u8> = registry::puzzles()
+        .iter()
+        .filter(|p| p.year == YEAR)
+        .map(|p| p.day)
+        .collect();
+    days.dedup();
+
+    if !out_days.is_null() {
+        let n = days.len().min(capacity);
+        slice::from_raw_parts_mut(out_days, n).copy_from_slice(&days[..n]);
+    }
+    days.len()
+}
+
+/// Returns a static, NUL terminated description of an error code.
+#[no_mangle]
+pub extern "C" fn aoc_strerror(code: i32) -> *const c_char {
+    let message = ErrorCode::ALL
+        .into_iter()
+        .find(|&c| c as i32 == code)
+        .map_or("unknown error code\0", ErrorCode::message);
+    message.as_ptr() as *const c_char
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn solve(day: u8, part: u8, input: &str, capacity: usize) -> (ErrorCode, String) {
+        let mut buf = vec![0 as c_char; capacity.max(1)];
+        let mut len = capacity;
+        let code = unsafe {
+            aoc_solve(
+                day,
+                part,
+                input.as_ptr(),
+                input.len(),
+                buf.as_mut_ptr(),
+                &mut len,
+            )
+        };
+        let written = buf[..len.min(capacity)].iter().map(|&c| c as u8).collect();
+        (code, String::from_utf8(written).unwrap())
+    }
+
+    #[test]
+    fn test_solve() {
+        assert_eq!(
+            solve(2, 1, "A Y\nB X\nC Z\n", 16),
+            (ErrorCode::Ok, String::from("15"))
+        );
+        assert_eq!(solve(2, 3, "A Y", 16).0, ErrorCode::UnknownPuzzle);
+        assert_eq!(
+            solve(2, 1, "A Y\nB X\nC Z\n", 2).0,
+            ErrorCode::BufferTooSmall
+        );
+        assert_eq!(solve(2, 1, "A W", 64).0, ErrorCode::InvalidInput);
+    }
+
+    #[test]
+    fn test_list_days() {
+        let mut days = [0_u8; 32];
+        let n = unsafe { aoc_list_days(days.as_mut_ptr(), days.len()) };
+        assert_eq!(&days[..n], &[1, 2, 3, 4, 5, 6, 7, 8, 9]);
+    }
+
+    #[test]
+    fn test_strerror() {
+        let message = |code| unsafe { std::ffi::CStr::from_ptr(aoc_strerror(code)) };
+        assert_eq!(message(2).to_str(), Ok("unknown day or part"));
+        assert_eq!(message(-1).to_str(), Ok("unknown error code"));
+    }
+}
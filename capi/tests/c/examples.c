@@ -0,0 +1,76 @@
+/* Solves every puzzle example through the C API, exits with the number of failures. */
+
+#include <stdio.h>
+#include <string.h>
+
+#include "aoc.h"
+
+struct example {
+    uint8_t day;
+    uint8_t part;
+    const char *input;
+    const char *expected;
+};
+
+static const char DAY01[] = "1000\n2000\n3000\n\n4000\n\n5000\n6000\n\n7000\n8000\n9000\n\n10000\n";
+static const char DAY02[] = "A Y\nB X\nC Z\n";
+static const char DAY03[] = "vJrwpWtwJgWrhcsFMMfFFhFp\njqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL\n"
+                            "PmmdzqPrVvPwwTWBwg\nwMqvLMZHhHMvwLHjbvcjnnSBnvTQFn\n"
+                            "ttgJtRGJQctTZtZT\nCrZsJsPPZsGzwwsLwLmpwMDw\n";
+static const char DAY04[] = "2-4,6-8\n2-3,4-5\n5-7,7-9\n2-8,3-7\n6-6,4-6\n2-6,4-8\n";
+static const char DAY05[] = "    [D]    \n[N] [C]    \n[Z] [M] [P]\n 1   2   3\n\n"
+                            "move 1 from 2 to 1\nmove 3 from 1 to 3\n"
+                            "move 2 from 2 to 1\nmove 1 from 1 to 2\n";
+static const char DAY06[] = "mjqjpqmgbljsphdztnvjfqwrcgsmlb\n";
+static const char DAY07[] = "$ cd /\n$ ls\ndir a\n14848514 b.txt\n8504156 c.dat\ndir d\n"
+                            "$ cd a\n$ ls\ndir e\n29116 f\n2557 g\n62596 h.lst\n$ cd e\n$ ls\n"
+                            "584 i\n$ cd ..\n$ cd ..\n$ cd d\n$ ls\n4060174 j\n8033020 d.log\n"
+                            "5626152 d.ext\n7214296 k\n";
+static const char DAY08[] = "30373\n25512\n65332\n33549\n35390\n";
+static const char DAY09[] = "R 4\nU 4\nL 3\nD 1\nR 4\nD 1\nL 5\nR 2\n";
+
+static const struct example EXAMPLES[] = {
+    {1, 1, DAY01, "24000"}, {1, 2, DAY01, "45000"},
+    {2, 1, DAY02, "15"},    {2, 2, DAY02, "12"},
+    {3, 1, DAY03, "157"},   {3, 2, DAY03, "70"},
+    {4, 1, DAY04, "2"},     {4, 2, DAY04, "4"},
+    {5, 1, DAY05, "CMZ"},   {5, 2, DAY05, "MCD"},
+    {6, 1, DAY06, "7"},     {6, 2, DAY06, "19"},
+    {7, 1, DAY07, "95437"}, {7, 2, DAY07, "24933642"},
+    {8, 1, DAY08, "21"},    {8, 2, DAY08, "8"},
+    {9, 1, DAY09, "13"},    {9, 2, DAY09, "1"},
+};
+
+int main(void) {
+    int failures = 0;
+    char out[64];
+
+    for (size_t i = 0; i < sizeof(EXAMPLES) / sizeof(EXAMPLES[0]); i++) {
+        const struct example *ex = &EXAMPLES[i];
+        size_t len = sizeof(out);
+        aoc_error_t err = aoc_solve(ex->day, ex->part, (const uint8_t *)ex->input,
+                                    strlen(ex->input), out, &len);
+        if (err != AOC_OK || strcmp(out, ex->expected) != 0) {
+            printf("day %d part %d: got %s (%s), expected %s\n", ex->day, ex->part,
+                   err == AOC_OK ? out : "error", aoc_strerror(err), ex->expected);
+            failures++;
+        }
+    }
+
+    uint8_t days[32];
+    size_t n = aoc_list_days(days, sizeof(days));
+    if (n != 9 || days[0] != 1 || days[8] != 9) {
+        printf("aoc_list_days: got %zu days\n", n);
+        failures++;
+    }
+
+    size_t len = 1;
+    if (aoc_solve(1, 1, (const uint8_t *)DAY01, strlen(DAY01), out, &len) !=
+            AOC_ERR_BUFFER_TOO_SMALL ||
+        len != 5) {
+        printf("aoc_solve: expected AOC_ERR_BUFFER_TOO_SMALL with a length of 5\n");
+        failures++;
+    }
+
+    return failures;
+}
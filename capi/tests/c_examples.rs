@@ -0,0 +1,42 @@
+// Builds `tests/c/examples.c` against the shared library and runs it. Skipped when no C compiler
+// is available.
+
+use std::env;
+use std::path::PathBuf;
+use std::process::Command;
+
+#[test]
+fn c_program_solves_examples() {
+    let cc = env::var("CC").unwrap_or_else(|_| String::from("cc"));
+    if Command::new(&cc).arg("--version").output().is_err() {
+        eprintln!("Skipping C API test, `{}` was not found", cc);
+        return;
+    }
+
+    // Test binaries live in target/<profile>/deps, the shared library in target/<profile>
+    let exe = env::current_exe().unwrap();
+    let lib_dir = exe.parent().unwrap().parent().unwrap().to_path_buf();
+    let manifest_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    let program = lib_dir.join("aoc_c_examples");
+
+    let status = Command::new(&cc)
+        .arg(manifest_dir.join("tests/c/examples.c"))
+        .arg("-I")
+        .arg(manifest_dir.join("include"))
+        .arg("-L")
+        .arg(&lib_dir)
+        .arg(format!("-Wl,-rpath,{}", lib_dir.display()))
+        .arg("-laoc")
+        .arg("-o")
+        .arg(&program)
+        .status()
+        .unwrap();
+    assert!(status.success(), "Failed to compile examples.c");
+
+    let output = Command::new(&program).output().unwrap();
+    assert!(
+        output.status.success(),
+        "C examples failed:\n{}",
+        String::from_utf8_lossy(&output.stdout)
+    );
+}
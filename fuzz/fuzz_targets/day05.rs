@@ -0,0 +1,10 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+// Solvers must return an answer or an error, any panic is reported as a crash
+fuzz_target!(|data: &[u8]| {
+    let lines = aoc2022::lines_from_bytes(data);
+    let _ = aoc2022::y2022::day05::part1(lines.clone());
+    let _ = aoc2022::y2022::day05::part2(lines);
+});
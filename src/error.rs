@@ -0,0 +1,38 @@
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    EmptyInput,
+    // Line numbers are 1-based, as shown by an editor
+    InvalidLine { line: usize, reason: String },
+    Overflow,
+    NoSolution(String),
+    // Rejected value given next to the input, like a path to look up
+    InvalidArgument(String),
+}
+
+impl Error {
+    // Builds an InvalidLine error from a 0-based line index
+    pub(crate) fn invalid_line(index: usize, reason: impl Into<String>) -> Self {
+        Error::InvalidLine {
+            line: index + 1,
+            reason: reason.into(),
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::EmptyInput => write!(f, "Input is empty"),
+            Error::InvalidLine { line, reason } => write!(f, "Invalid line {}: {}", line, reason),
+            Error::Overflow => write!(f, "Arithmetic overflow"),
+            Error::NoSolution(reason) => write!(f, "No solution: {}", reason),
+            Error::InvalidArgument(reason) => write!(f, "Invalid argument: {}", reason),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+pub type Result<T> = std::result::Result<T, Error>;
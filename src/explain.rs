@@ -0,0 +1,186 @@
+// Trace events emitted by the solvers to explain how they got to their answer. Events are only
+// formatted when explain mode is turned on (`--explain` on the command line), otherwise the
+// `explain!` macro is a single atomic load.
+
+use std::sync::atomic::{AtomicU8, Ordering};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Mode {
+    Off,
+    Text,
+    Json,
+}
+
+static MODE: AtomicU8 = AtomicU8::new(Mode::Off as u8);
+
+pub fn set_mode(mode: Mode) {
+    MODE.store(mode as u8, Ordering::Relaxed);
+}
+
+pub fn mode() -> Mode {
+    match MODE.load(Ordering::Relaxed) {
+        1 => Mode::Text,
+        2 => Mode::Json,
+        _ => Mode::Off,
+    }
+}
+
+#[inline]
+pub fn enabled() -> bool {
+    MODE.load(Ordering::Relaxed) != Mode::Off as u8
+}
+
+// Field value of an event. Only numeric fields are written as JSON numbers, so that a name like
+// `007` stays a string.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Value {
+    Number(String),
+    Text(String),
+}
+
+pub trait ToValue {
+    fn to_value(&self) -> Value;
+}
+
+macro_rules! number_values {
+    ($($t:ty),*) => {
+        $(impl ToValue for $t {
+            fn to_value(&self) -> Value {
+                Value::Number(self.to_string())
+            }
+        })*
+    };
+}
+
+number_values!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+impl ToValue for str {
+    fn to_value(&self) -> Value {
+        Value::Text(self.to_owned())
+    }
+}
+
+impl ToValue for String {
+    fn to_value(&self) -> Value {
+        Value::Text(self.clone())
+    }
+}
+
+impl ToValue for char {
+    fn to_value(&self) -> Value {
+        Value::Text(self.to_string())
+    }
+}
+
+impl<T: ToValue + ?Sized> ToValue for &T {
+    fn to_value(&self) -> Value {
+        (**self).to_value()
+    }
+}
+
+// Emits an event to stderr. `source` is a module path, only the day is kept (the last segment, or
+// the enclosing day of a submodule like `day05::simulation`).
+pub fn emit(source: &str, event: &str, fields: &[(&str, Value)]) {
+    let day = source
+        .rsplit("::")
+        .find(|segment| segment.starts_with("day"))
+        .unwrap_or_else(|| source.rsplit("::").next().unwrap_or(source));
+    if let Some(line) = format_event(mode(), day, event, fields) {
+        eprintln!("{}", line);
+    }
+}
+
+fn format_event(mode: Mode, day: &str, event: &str, fields: &[(&str, Value)]) -> Option<String> {
+    match mode {
+        Mode::Off => None,
+        Mode::Text => {
+            let mut line = format!("[{}] {}", day, event);
+            for (key, value) in fields {
+                let (Value::Number(value) | Value::Text(value)) = value;
+                line.push_str(&format!(" {}={}", key, value));
+            }
+            Some(line)
+        }
+        Mode::Json => {
+            let mut line = format!(
+                "{{\"day\":{},\"event\":{}",
+                json_string(day),
+                json_string(event)
+            );
+            for (key, value) in fields {
+                let value = match value {
+                    Value::Number(number) => number.clone(),
+                    Value::Text(text) => json_string(text),
+                };
+                line.push_str(&format!(",{}:{}", json_string(key), value));
+            }
+            line.push('}');
+            Some(line)
+        }
+    }
+}
+
+fn json_string(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len() + 2);
+    escaped.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            c if (c as u32) < 0x20 => escaped.push_str(&format!("\\u{:04x}", c as u32)),
+            c => escaped.push(c),
+        }
+    }
+    escaped.push('"');
+    escaped
+}
+
+// Usage: `explain!("elf_total", elf = 3, total = 6000);`
+// Values are integers, strings or chars (see `ToValue`), and are not evaluated when explain mode
+// is off.
+#[macro_export]
+macro_rules! explain {
+    ($event:expr $(, $key:ident = $value:expr)* $(,)?) => {
+        if $crate::explain::enabled() {
+            $crate::explain::emit(
+                module_path!(),
+                $event,
+                &[$((stringify!($key), $crate::explain::ToValue::to_value(&$value))),*],
+            );
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_text() {
+        let fields = [("elf", 2.to_value()), ("total", 4000_u64.to_value())];
+        assert_eq!(
+            format_event(Mode::Text, "day01", "elf_total", &fields),
+            Some(String::from("[day01] elf_total elf=2 total=4000"))
+        );
+        assert_eq!(format_event(Mode::Off, "day01", "elf_total", &fields), None);
+    }
+
+    #[test]
+    fn test_format_json() {
+        let fields = [("round", 1.to_value()), ("pick", "\"Rock\"".to_value())];
+        assert_eq!(
+            format_event(Mode::Json, "day02", "round", &fields),
+            Some(String::from(
+                r#"{"day":"day02","event":"round","round":1,"pick":"\"Rock\""}"#
+            ))
+        );
+        let fields = [("name", "007".to_value()), ("size", "12".to_value())];
+        assert_eq!(
+            format_event(Mode::Json, "day07", "dir_size", &fields),
+            Some(String::from(
+                r#"{"day":"day07","event":"dir_size","name":"007","size":"12"}"#
+            ))
+        );
+    }
+}
@@ -1,15 +1,14 @@
 use std::fs;
 
-pub mod day01;
-pub mod day02;
-pub mod day03;
-pub mod day04;
-pub mod day05;
-pub mod day06;
-pub mod day07;
-pub mod day07_alt;
-pub mod day08;
-pub mod day09;
+pub mod error;
+pub mod explain;
+pub mod registry;
+pub mod rng;
+#[doc(hidden)]
+pub mod testing;
+pub mod y2022;
+
+pub use error::{Error, Result};
 
 pub fn read_and_parse_file(path: &str) -> Vec<String> {
     // Reads a file and returns Line iterator
@@ -19,3 +18,11 @@ pub fn read_and_parse_file(path: &str) -> Vec<String> {
         .map(String::from)
         .collect()
 }
+
+pub fn lines_from_bytes(data: &[u8]) -> Vec<String> {
+    // Same as read_and_parse_file, for raw (possibly invalid UTF-8) input
+    String::from_utf8_lossy(data)
+        .lines()
+        .map(String::from)
+        .collect()
+}
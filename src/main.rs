@@ -1,91 +1,348 @@
-use std::env;
+use std::io::{self, IsTerminal};
+use std::time::Duration;
+use std::{env, fmt::Display, path::Path, process, thread};
 
-use aoc2022::read_and_parse_file;
+use aoc2022::{explain, read_and_parse_file, registry, y2022};
 
-fn main() {
-    let args: Vec<String> = env::args().skip(1).collect();
+#[derive(Default)]
+struct Options {
+    explain: Option<explain::Mode>,
+    // Day 1: number of elves to report
+    k: Option<usize>,
+    // Day 2: score the guide under every decoding, optionally looking for a target score
+    analyze: bool,
+    target: Option<u32>,
+    // Day 4: draw the pairs, optionally only a range of lines
+    render: bool,
+    render_lines: Option<(usize, usize)>,
+    // Day 5: redraw the stacks after every procedure, waiting `delay` milliseconds
+    animate: bool,
+    delay: Option<u64>,
+    // Day 7: print the filesystem like `tree` or `du`, down to `depth` and keeping the `top`
+    // largest entries
+    tree: bool,
+    du: bool,
+    depth: Option<usize>,
+    top: Option<usize>,
+}
 
-    match args.get(0).unwrap().as_str() {
-        "1a" => {
-            let input = read_and_parse_file("data/day01.txt");
-            println!("{}", aoc2022::day01::part1(input));
-        }
-        "1b" => {
-            let input = read_and_parse_file("data/day01.txt");
-            println!("{}", aoc2022::day01::part2(input));
-        }
-        "2a" => {
-            let input = read_and_parse_file("data/day02.txt");
-            println!("{}", aoc2022::day02::part1(input));
-        }
-        "2b" => {
-            let input = read_and_parse_file("data/day02.txt");
-            println!("{}", aoc2022::day02::part2(input));
-        }
-        "3a" => {
-            let input = read_and_parse_file("data/day03.txt");
-            println!("{}", aoc2022::day03::part1(input));
-        }
-        "3b" => {
-            let input = read_and_parse_file("data/day03.txt");
-            println!("{}", aoc2022::day03::part2(input));
-        }
-        "4a" => {
-            let input = read_and_parse_file("data/day04.txt");
-            println!("{}", aoc2022::day04::part1(input));
-        }
-        "4b" => {
-            let input = read_and_parse_file("data/day04.txt");
-            println!("{}", aoc2022::day04::part2(input));
-        }
-        "5a" => {
-            let input = read_and_parse_file("data/day05.txt");
-            println!("{}", aoc2022::day05::part1(input));
-        }
-        "5b" => {
-            let input = read_and_parse_file("data/day05.txt");
-            println!("{}", aoc2022::day05::part2(input));
-        }
-        "6a" => {
-            let input = read_and_parse_file("data/day06.txt");
-            println!("{}", aoc2022::day06::part1(input));
-        }
-        "6b" => {
-            let input = read_and_parse_file("data/day06.txt");
-            println!("{}", aoc2022::day06::part2(input));
+fn fail(message: &str) -> ! {
+    eprintln!("{}", message);
+    process::exit(1);
+}
+
+fn print_answer<T: Display>(answer: aoc2022::Result<T>) {
+    match answer {
+        Ok(answer) => println!("{}", answer),
+        Err(e) => fail(&e.to_string()),
+    }
+}
+
+// Value of an option, either given inline (`--name=value`) or as the next argument
+fn option_value(
+    name: &str,
+    inline: Option<&str>,
+    args: &mut impl Iterator<Item = String>,
+) -> Result<String, String> {
+    inline
+        .map(String::from)
+        .or_else(|| args.next())
+        .ok_or_else(|| format!("Missing value for --{}", name))
+}
+
+// Line range as `first-last` or a single line number
+fn parse_line_range(range: &str) -> Option<(usize, usize)> {
+    match range.split_once('-') {
+        Some((first, last)) => Some((first.parse().ok()?, last.parse().ok()?)),
+        None => range.parse().ok().map(|line| (line, line)),
+    }
+}
+
+// Splits options (`--name`, `--name=value` or `--name value`) from the positional arguments
+fn parse_args(mut args: impl Iterator<Item = String>) -> Result<(Options, Vec<String>), String> {
+    let mut options = Options::default();
+    let mut positional = Vec::new();
+
+    while let Some(arg) = args.next() {
+        let Some(option) = arg.strip_prefix("--") else {
+            positional.push(arg);
+            continue;
+        };
+        let (name, inline) = match option.split_once('=') {
+            Some((name, value)) => (name, Some(value)),
+            None => (option, None),
+        };
+
+        match (name, inline) {
+            ("explain", None | Some("text")) => options.explain = Some(explain::Mode::Text),
+            ("explain", Some("json")) => options.explain = Some(explain::Mode::Json),
+            ("k", _) => {
+                let k = option_value(name, inline, &mut args)?;
+                options.k = Some(
+                    k.parse()
+                        .map_err(|_| format!("Invalid value for --k: {}", k))?,
+                );
+            }
+            ("analyze", None) => options.analyze = true,
+            ("target", _) => {
+                let target = option_value(name, inline, &mut args)?;
+                options.target = Some(
+                    target
+                        .parse()
+                        .map_err(|_| format!("Invalid value for --target: {}", target))?,
+                );
+            }
+            ("render", None) => options.render = true,
+            ("render", Some(lines)) => {
+                options.render = true;
+                options.render_lines = Some(
+                    parse_line_range(lines)
+                        .ok_or_else(|| format!("Invalid value for --render: {}", lines))?,
+                );
+            }
+            ("animate", None) => options.animate = true,
+            ("delay", _) => {
+                let delay = option_value(name, inline, &mut args)?;
+                options.delay = Some(
+                    delay
+                        .parse()
+                        .map_err(|_| format!("Invalid value for --delay: {}", delay))?,
+                );
+            }
+            ("tree", None) => options.tree = true,
+            ("du", None) => options.du = true,
+            ("depth", _) => {
+                let depth = option_value(name, inline, &mut args)?;
+                options.depth = Some(
+                    depth
+                        .parse()
+                        .map_err(|_| format!("Invalid value for --depth: {}", depth))?,
+                );
+            }
+            ("top", _) => {
+                let top = option_value(name, inline, &mut args)?;
+                options.top = Some(
+                    top.parse()
+                        .map_err(|_| format!("Invalid value for --top: {}", top))?,
+                );
+            }
+            _ => return Err(format!("Unknown option --{}", option)),
         }
-        "7a" => {
-            let input = read_and_parse_file("data/day07.txt");
-            println!("{}", aoc2022::day07::part1(input));
+    }
+    Ok((options, positional))
+}
+
+fn load_input(year: u16, day: u8) -> Vec<String> {
+    let path = registry::data_path(Path::new("data"), year, day);
+    if !path.exists() {
+        fail(&format!("Missing puzzle input {}", path.display()));
+    }
+    read_and_parse_file(&path.to_string_lossy())
+}
+
+fn require_day(year: u16, day: Option<u8>, expected: u8, option: &str) {
+    if year != 2022 || day != Some(expected) {
+        fail(&format!(
+            "{} is only available for day {} of 2022",
+            option, expected
+        ));
+    }
+}
+
+fn top_k_report(lines: &[String], k: usize) {
+    match y2022::day01::top_k_elves(lines, k) {
+        Ok(elves) => {
+            for elf in &elves {
+                println!(
+                    "Elf {}: {} calories ({} items)",
+                    elf.index + 1,
+                    elf.total,
+                    elf.items
+                );
+            }
+            print_answer(y2022::day01::top_k_total(lines, k));
         }
-        "7b" => {
-            let input = read_and_parse_file("data/day07.txt");
-            println!("{}", aoc2022::day07::part2(input));
+        Err(e) => fail(&e.to_string()),
+    }
+}
+
+fn analyze_report(lines: &[String], target: Option<u32>) {
+    use y2022::day02::{analyze, HandGame};
+
+    let analysis = analyze::analyze(lines).unwrap_or_else(|e| fail(&e.to_string()));
+    for evaluation in &analysis.evaluations {
+        println!("{:>8}  {}", evaluation.total, evaluation.decoding);
+    }
+    let best = analysis.best();
+    let worst = analysis.worst();
+    println!("Best: {} ({})", best.decoding, best.total);
+    println!("Worst: {} ({})", worst.decoding, worst.total);
+
+    if let Some(target) = target {
+        let matching = analysis.matching(target);
+        if matching.is_empty() {
+            println!("No decoding scores {}", target);
         }
-        "7aalt" => {
-            let input = read_and_parse_file("data/day07.txt");
-            println!("{}", aoc2022::day07_alt::part1(input));
+        for evaluation in matching {
+            println!("Scores {}: {}", target, evaluation.decoding);
         }
-        "7balt" => {
-            let input = read_and_parse_file("data/day07.txt");
-            println!("{}", aoc2022::day07_alt::part2(input));
+    }
+
+    let game = HandGame::rock_paper_scissors();
+    println!("Rounds with the best decoding:");
+    for round in &best.rounds {
+        println!(
+            "Line {}: {} vs {}, {:?}, {} points",
+            round.line,
+            game.name(round.player).unwrap_or("?"),
+            game.name(round.opponent).unwrap_or("?"),
+            round.outcome,
+            round.score
+        );
+    }
+}
+
+fn animate(lines: &[String], part: registry::Part, delay: Duration) {
+    use y2022::day05::{render_stacks, Crane, CrateMover9000, CrateMover9001, Highlight};
+
+    let crane: Box<dyn Crane> = match part {
+        registry::Part::One => Box::new(CrateMover9000),
+        registry::Part::Two => Box::new(CrateMover9001),
+    };
+    let mut simulation =
+        y2022::day05::Simulation::from_lines(crane, lines).unwrap_or_else(|e| fail(&e.to_string()));
+    // Only clear the screen and use colours on a terminal, so that the output can be piped
+    let terminal = io::stdout().is_terminal();
+    let draw = |stacks: &[Vec<y2022::day05::Crate>], title: &str, highlight: Option<Highlight>| {
+        if terminal {
+            print!("\x1b[2J\x1b[H");
         }
-        "8a" => {
-            let input = read_and_parse_file("data/day08.txt");
-            println!("{}", aoc2022::day08::part1(input));
+        println!("{}\n", title);
+        for line in render_stacks(stacks, highlight.filter(|_| terminal)) {
+            println!("{}", line);
         }
-        "8b" => {
-            let input = read_and_parse_file("data/day08.txt");
-            println!("{}", aoc2022::day08::part2(input));
+        println!();
+    };
+
+    draw(simulation.stacks(), simulation.crane().name(), None);
+    for step in 0..simulation.len() {
+        thread::sleep(delay);
+        if let Err(e) = simulation.redo() {
+            fail(&e.to_string());
         }
-        "9a" => {
-            let input = read_and_parse_file("data/day09.txt");
-            println!("{}", aoc2022::day09::part1(input));
+        let (_, procedure) = simulation.procedures()[step];
+        let highlight = Highlight {
+            stack: procedure.destination,
+            count: procedure.quantity,
+        };
+        let title = format!("{} ({}/{})", procedure, step + 1, simulation.len());
+        draw(simulation.stacks(), &title, Some(highlight));
+    }
+    println!("{}", simulation.top_crates());
+}
+
+fn filesystem_report<B: y2022::day07::filesystem::FileSystemBuilder>(
+    lines: &[String],
+    tree: bool,
+    options: &y2022::day07::report::ReportOptions,
+) -> Vec<String> {
+    use y2022::day07::{filesystem, report};
+
+    let filesystem: B =
+        filesystem::parse_transcript(lines).unwrap_or_else(|e| fail(&e.to_string()));
+    if tree {
+        report::tree(&filesystem, options)
+    } else {
+        report::du(&filesystem, options)
+    }
+}
+
+fn main() {
+    let (options, args) = parse_args(env::args().skip(1)).unwrap_or_else(|e| fail(&e));
+    if let Some(mode) = options.explain {
+        explain::set_mode(mode);
+    }
+
+    // Usage: aoc2022 [year] <day><part>, year defaults to the latest one
+    let (year, spec) = match &args[..] {
+        [year, spec] => match year.parse::<u16>() {
+            Ok(year) => (year, spec.as_str()),
+            Err(_) => fail("Invalid year specified"),
+        },
+        [spec] => (registry::LATEST_YEAR, spec.as_str()),
+        _ => fail("Usage: aoc2022 [year] <day><part> [options] (ex: aoc2022 2022 5b)"),
+    };
+    // Day specific reports don't need a part (ex: `aoc2022 1 --k 5`)
+    let day = spec
+        .trim_end_matches(|c: char| c.is_ascii_alphabetic())
+        .parse::<u8>()
+        .ok();
+
+    if let Some(k) = options.k {
+        require_day(year, day, 1, "--k");
+        top_k_report(&load_input(year, 1), k);
+        return;
+    }
+    if options.analyze {
+        require_day(year, day, 2, "--analyze");
+        analyze_report(&load_input(year, 2), options.target);
+        return;
+    }
+    if options.render {
+        require_day(year, day, 4, "--render");
+        let render_options = y2022::day04::render::RenderOptions {
+            color: io::stdout().is_terminal(),
+            lines: options.render_lines,
+            ..Default::default()
+        };
+        let drawing = y2022::day04::render::render(&load_input(year, 4), &render_options);
+        print!("{}", drawing.unwrap_or_else(|e| fail(&e.to_string())));
+        return;
+    }
+    if options.animate {
+        let part = match registry::parse_spec(spec) {
+            Some((5, part, None)) if year == 2022 => part,
+            _ => fail("--animate is only available for day 5 of 2022, with a part (ex: 5a)"),
+        };
+        let delay = Duration::from_millis(options.delay.unwrap_or(500));
+        animate(&load_input(year, 5), part, delay);
+        return;
+    }
+    if options.tree || options.du {
+        require_day(year, day, 7, if options.tree { "--tree" } else { "--du" });
+        if options.tree && options.du {
+            fail("--tree and --du can't be used together");
         }
-        "9b" => {
-            let input = read_and_parse_file("data/day09.txt");
-            println!("{}", aoc2022::day09::part2(input));
+        let report_options = y2022::day07::report::ReportOptions {
+            max_depth: options.depth,
+            top: options.top,
+        };
+        let lines = load_input(year, 7);
+        // `7alt` uses the arena filesystem of day07_alt
+        let report = if spec.ends_with("alt") {
+            filesystem_report::<y2022::day07_alt::FileSystem>(&lines, options.tree, &report_options)
+        } else {
+            filesystem_report::<y2022::day07::FsTree>(&lines, options.tree, &report_options)
+        };
+        for line in report {
+            println!("{}", line);
         }
-        _ => eprintln!("Invalid day specified"),
+        return;
+    }
+    if options.depth.is_some() || options.top.is_some() {
+        fail("--depth and --top can only be used with --tree or --du");
+    }
+    if options.delay.is_some() {
+        fail("--delay can only be used with --animate");
+    }
+    if options.target.is_some() {
+        fail("--target can only be used with --analyze");
+    }
+
+    let puzzle = registry::parse_spec(spec)
+        .and_then(|(day, part, variant)| registry::find(year, day, part, variant));
+    match puzzle {
+        Some(puzzle) => print_answer((puzzle.solve)(load_input(year, puzzle.day))),
+        None => fail("Invalid day specified"),
     }
 }
@@ -0,0 +1,121 @@
+// Every available solver, indexed by year, day and part, and where to find their inputs.
+
+use std::path::{Path, PathBuf};
+
+use crate::{y2022, Result};
+
+pub const LATEST_YEAR: u16 = 2022;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Part {
+    One,
+    Two,
+}
+
+pub type Solver = fn(Vec<String>) -> Result<String>;
+
+pub struct Puzzle {
+    pub year: u16,
+    pub day: u8,
+    pub part: Part,
+    // Alternative implementations of the same puzzle (ex: `alt` for day07_alt)
+    pub variant: Option<&'static str>,
+    pub solve: Solver,
+}
+
+macro_rules! puzzle {
+    ($year:expr, $day:expr, $part:ident, $variant:expr, $solver:path) => {
+        Puzzle {
+            year: $year,
+            day: $day,
+            part: Part::$part,
+            variant: $variant,
+            solve: |lines| $solver(lines).map(|answer| answer.to_string()),
+        }
+    };
+}
+
+static PUZZLES: &[Puzzle] = &[
+    puzzle!(2022, 1, One, None, y2022::day01::part1),
+    puzzle!(2022, 1, Two, None, y2022::day01::part2),
+    puzzle!(2022, 2, One, None, y2022::day02::part1),
+    puzzle!(2022, 2, Two, None, y2022::day02::part2),
+    puzzle!(2022, 3, One, None, y2022::day03::part1),
+    puzzle!(2022, 3, Two, None, y2022::day03::part2),
+    puzzle!(2022, 4, One, None, y2022::day04::part1),
+    puzzle!(2022, 4, Two, None, y2022::day04::part2),
+    puzzle!(2022, 5, One, None, y2022::day05::part1),
+    puzzle!(2022, 5, Two, None, y2022::day05::part2),
+    puzzle!(2022, 6, One, None, y2022::day06::part1),
+    puzzle!(2022, 6, Two, None, y2022::day06::part2),
+    puzzle!(2022, 7, One, None, y2022::day07::part1),
+    puzzle!(2022, 7, Two, None, y2022::day07::part2),
+    puzzle!(2022, 7, One, Some("alt"), y2022::day07_alt::part1),
+    puzzle!(2022, 7, Two, Some("alt"), y2022::day07_alt::part2),
+    puzzle!(2022, 8, One, None, y2022::day08::part1),
+    puzzle!(2022, 8, Two, None, y2022::day08::part2),
+    puzzle!(2022, 9, One, None, y2022::day09::part1),
+    puzzle!(2022, 9, Two, None, y2022::day09::part2),
+];
+
+pub fn puzzles() -> &'static [Puzzle] {
+    PUZZLES
+}
+
+pub fn find(year: u16, day: u8, part: Part, variant: Option<&str>) -> Option<&'static Puzzle> {
+    PUZZLES
+        .iter()
+        .find(|p| p.year == year && p.day == day && p.part == part && p.variant == variant)
+}
+
+// Parses a puzzle spec as given on the command line: day number, part (a or b) and an optional
+// variant name (ex: `5a`, `12b` or `7balt`)
+pub fn parse_spec(spec: &str) -> Option<(u8, Part, Option<&str>)> {
+    let digits = spec.find(|c: char| !c.is_ascii_digit())?;
+    let day = spec[..digits].parse().ok()?;
+    let mut rest = spec[digits..].chars();
+    let part = match rest.next()? {
+        'a' => Part::One,
+        'b' => Part::Two,
+        _ => return None,
+    };
+    let variant = Some(rest.as_str()).filter(|v| !v.is_empty());
+    Some((day, part, variant))
+}
+
+// Inputs are stored as `data/<year>/dayXX.txt`. Before multiple years were supported, 2022
+// inputs were stored directly as `data/dayXX.txt`, which is still used if it's the only one.
+pub fn data_path(data_dir: &Path, year: u16, day: u8) -> PathBuf {
+    let file_name = format!("day{:02}.txt", day);
+    let path = data_dir.join(year.to_string()).join(&file_name);
+    let legacy_path = data_dir.join(&file_name);
+    if year == 2022 && !path.exists() && legacy_path.exists() {
+        legacy_path
+    } else {
+        path
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_spec() {
+        assert_eq!(parse_spec("5a"), Some((5, Part::One, None)));
+        assert_eq!(parse_spec("12b"), Some((12, Part::Two, None)));
+        assert_eq!(parse_spec("7balt"), Some((7, Part::Two, Some("alt"))));
+        assert_eq!(parse_spec("5"), None);
+        assert_eq!(parse_spec("5c"), None);
+        assert_eq!(parse_spec("a"), None);
+    }
+
+    #[test]
+    fn test_find() {
+        let lines = ["A Y", "B X", "C Z"].map(String::from).to_vec();
+        let puzzle = find(2022, 2, Part::One, None).unwrap();
+        assert_eq!((puzzle.solve)(lines), Ok(String::from("15")));
+        assert!(find(2022, 7, Part::Two, Some("alt")).is_some());
+        assert!(find(2021, 1, Part::One, None).is_none());
+    }
+}
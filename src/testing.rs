@@ -0,0 +1,54 @@
+// Seeded day03 inputs shared by the benchmarks and the differential tests. Not part of the puzzle
+// API.
+
+pub mod day03 {
+    use std::ops::RangeInclusive;
+
+    use crate::rng::Rng;
+
+    // Item types, by increasing priority
+    pub const LETTERS: &[u8] = b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+    fn size(rng: &mut Rng, sizes: &RangeInclusive<usize>) -> usize {
+        rng.range(*sizes.start() as u64, *sizes.end() as u64) as usize
+    }
+
+    // Rucksack with compartments of a size in `sizes` (at least 1). The alphabet is split into
+    // two disjoint pools so that only one item type is in both compartments.
+    pub fn rucksack(rng: &mut Rng, sizes: RangeInclusive<usize>) -> String {
+        let mut letters = LETTERS.to_vec();
+        rng.shuffle(&mut letters);
+        let common = letters[0];
+        let half = size(rng, &sizes);
+        let mut h1: Vec<u8> = (1..half).map(|_| *rng.pick(&letters[1..26])).collect();
+        let mut h2: Vec<u8> = (1..half).map(|_| *rng.pick(&letters[26..])).collect();
+        h1.push(common);
+        h2.push(common);
+        rng.shuffle(&mut h1);
+        rng.shuffle(&mut h2);
+        String::from_utf8([h1, h2].concat()).expect("ASCII letters")
+    }
+
+    // Group of three rucksacks of a size in `sizes` (at least 1). Each line draws from its own
+    // pool and the next one, so that item types are shared between pairs of lines but only the
+    // badge is carried by the whole group.
+    pub fn group(rng: &mut Rng, sizes: RangeInclusive<usize>) -> Vec<String> {
+        let mut letters = LETTERS.to_vec();
+        rng.shuffle(&mut letters);
+        let badge = letters[0];
+        let pools = [&letters[1..18], &letters[18..35], &letters[35..52]];
+        (0..3)
+            .map(|i| {
+                let mut line: Vec<u8> = (1..size(rng, &sizes))
+                    .map(|_| {
+                        let pool = pools[(i + rng.below(2)) % 3];
+                        *rng.pick(pool)
+                    })
+                    .collect();
+                line.push(badge);
+                rng.shuffle(&mut line);
+                String::from_utf8(line).expect("ASCII letters")
+            })
+            .collect()
+    }
+}
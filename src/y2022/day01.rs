@@ -0,0 +1,290 @@
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+use crate::{explain, Error, Result};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Elf {
+    pub items: Vec<u64>,
+    pub line: usize, // Line of the first item (or of the separator for empty elves), starting at 1
+}
+
+impl Elf {
+    pub fn total(&self) -> Result<u64> {
+        self.items
+            .iter()
+            .try_fold(0_u64, |acc, &n| acc.checked_add(n))
+            .ok_or(Error::Overflow)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Inventory {
+    pub elves: Vec<Elf>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ElfTotal {
+    pub index: usize, // Position of the elf in the input, starting at 0
+    pub total: u64,
+    pub items: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Anomaly {
+    // Elf without any item, usually from consecutive or trailing blank lines
+    EmptyElf { index: usize, line: usize },
+    ZeroCalorieItem { index: usize, line: usize },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Bucket {
+    pub start: u64, // Inclusive
+    pub end: u64,   // Inclusive
+    pub count: usize,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Report {
+    pub totals: Vec<u64>, // In input order
+    pub item_counts: Vec<usize>,
+    pub sum: u64,
+    pub mean: f64,
+    pub median: f64,
+    pub percentiles: Vec<(u8, u64)>, // (percentile, smallest total reaching it)
+    pub histogram: Vec<Bucket>,
+    pub anomalies: Vec<Anomaly>,
+}
+
+const PERCENTILES: [u8; 6] = [10, 25, 50, 75, 90, 99];
+const HISTOGRAM_BUCKETS: usize = 10;
+
+impl Inventory {
+    pub fn parse(lines: &[String]) -> Result<Self> {
+        // Every blank line ends the current elf, so blank lines in a row give empty elves
+        let mut elves = Vec::new();
+        let mut curr_elf = Elf {
+            items: Vec::new(),
+            line: 1,
+        };
+        for (i, line) in lines.iter().enumerate() {
+            match line.as_str() {
+                "" => {
+                    // After a trailing separator there is no next line, point to the separator
+                    let next = Elf {
+                        items: Vec::new(),
+                        line: (i + 2).min(lines.len()),
+                    };
+                    elves.push(std::mem::replace(&mut curr_elf, next));
+                }
+                str => {
+                    let n = str
+                        .parse::<u64>()
+                        .map_err(|e| Error::invalid_line(i, e.to_string()))?;
+                    if curr_elf.items.is_empty() {
+                        curr_elf.line = i + 1;
+                    }
+                    curr_elf.items.push(n);
+                }
+            }
+        }
+        elves.push(curr_elf);
+        Ok(Inventory { elves })
+    }
+
+    pub fn totals(&self) -> Result<Vec<u64>> {
+        self.elves.iter().map(Elf::total).collect()
+    }
+
+    // Returns the k elves carrying the most calories, most calories first. Elves with the same
+    // total are ranked by their position in the input.
+    pub fn top_k(&self, k: usize) -> Result<Vec<ElfTotal>> {
+        // Min-heap of the best k elves seen so far, the worst one is on top and gets replaced.
+        // Ranking key is (total, Reverse(index)) so that earlier elves win ties.
+        let mut heap: BinaryHeap<Reverse<(u64, Reverse<usize>, usize)>> =
+            BinaryHeap::with_capacity(k.min(self.elves.len()).saturating_add(1));
+        for (index, elf) in self.elves.iter().enumerate() {
+            let total = elf.total()?;
+            explain!("elf_total", elf = index + 1, total = total);
+            heap.push(Reverse((total, Reverse(index), elf.items.len())));
+            if heap.len() > k {
+                heap.pop();
+            }
+        }
+
+        Ok(heap
+            .into_sorted_vec()
+            .into_iter()
+            .map(|Reverse((total, Reverse(index), items))| ElfTotal {
+                index,
+                total,
+                items,
+            })
+            .collect())
+    }
+
+    pub fn anomalies(&self) -> Vec<Anomaly> {
+        let mut anomalies = Vec::new();
+        for (index, elf) in self.elves.iter().enumerate() {
+            if elf.items.is_empty() {
+                anomalies.push(Anomaly::EmptyElf {
+                    index,
+                    line: elf.line,
+                });
+            }
+            for (i, _) in elf.items.iter().enumerate().filter(|(_, &n)| n == 0) {
+                anomalies.push(Anomaly::ZeroCalorieItem {
+                    index,
+                    line: elf.line + i,
+                });
+            }
+        }
+        anomalies
+    }
+
+    pub fn report(&self) -> Result<Report> {
+        let totals = self.totals()?;
+        let sum = totals
+            .iter()
+            .try_fold(0_u64, |acc, &n| acc.checked_add(n))
+            .ok_or(Error::Overflow)?;
+        let mut sorted = totals.clone();
+        sorted.sort_unstable();
+
+        // There is always at least one elf, even for an empty input
+        let n = sorted.len();
+        let median = if n % 2 == 1 {
+            sorted[n / 2] as f64
+        } else {
+            (sorted[n / 2 - 1] as f64 + sorted[n / 2] as f64) / 2.0
+        };
+        // Nearest-rank percentiles
+        let percentiles = PERCENTILES
+            .iter()
+            .map(|&p| {
+                let rank = (p as usize * n).div_ceil(100).max(1);
+                (p, sorted[rank - 1])
+            })
+            .collect();
+
+        Ok(Report {
+            item_counts: self.elves.iter().map(|elf| elf.items.len()).collect(),
+            sum,
+            mean: sum as f64 / n as f64,
+            median,
+            percentiles,
+            histogram: histogram(&sorted, HISTOGRAM_BUCKETS),
+            anomalies: self.anomalies(),
+            totals,
+        })
+    }
+}
+
+// Splits the range of totals in `buckets` buckets of equal width
+fn histogram(sorted: &[u64], buckets: usize) -> Vec<Bucket> {
+    let (min, max) = (sorted[0], sorted[sorted.len() - 1]);
+    let width = ((max - min) / buckets as u64 + 1).max(1);
+    let mut histogram: Vec<Bucket> = (0..buckets as u64)
+        .map(|b| Bucket {
+            start: min.saturating_add(b.saturating_mul(width)),
+            end: min.saturating_add((b + 1).saturating_mul(width) - 1),
+            count: 0,
+        })
+        .take_while(|bucket| bucket.start <= max)
+        .collect();
+    for &total in sorted {
+        histogram[((total - min) / width) as usize].count += 1;
+    }
+    histogram
+}
+
+pub fn top_k_elves(lines: &[String], k: usize) -> Result<Vec<ElfTotal>> {
+    Inventory::parse(lines)?.top_k(k)
+}
+
+// Total calories carried by the top k elves
+pub fn top_k_total(lines: &[String], k: usize) -> Result<u64> {
+    top_k_elves(lines, k)?
+        .iter()
+        .try_fold(0_u64, |acc, elf| acc.checked_add(elf.total))
+        .ok_or(Error::Overflow)
+}
+
+pub fn part1(lines: Vec<String>) -> Result<u64> {
+    top_k_total(&lines, 1)
+}
+
+pub fn part2(lines: Vec<String>) -> Result<u64> {
+    top_k_total(&lines, 3)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn test_part1() {
+        let data: Vec<String> = [
+            "1000", "2000", "3000", "", "4000", "", "5000", "6000", "", "7000", "8000", "9000", "",
+            "10000",
+        ]
+        .map(String::from)
+        .to_vec();
+        assert_eq!(part1(data), Ok(24000));
+    }
+
+    #[test]
+    fn test_part2() {
+        let data: Vec<String> = [
+            "1000", "2000", "3000", "", "4000", "", "5000", "6000", "", "7000", "8000", "9000", "",
+            "10000",
+        ]
+        .map(String::from)
+        .to_vec();
+        assert_eq!(part2(data), Ok(45000));
+    }
+
+    #[test]
+    fn test_top_k_elves() {
+        let data: Vec<String> = ["1000", "", "3000", "", "1000", "2000", "", "500"]
+            .map(String::from)
+            .to_vec();
+        let elf = |index, total, items| ElfTotal {
+            index,
+            total,
+            items,
+        };
+        // Elves 1 and 2 are tied, the first one in the input ranks first
+        assert_eq!(
+            top_k_elves(&data, 3),
+            Ok(vec![elf(1, 3000, 1), elf(2, 3000, 2), elf(0, 1000, 1)])
+        );
+        assert_eq!(top_k_elves(&data, 0), Ok(vec![]));
+        assert_eq!(top_k_elves(&data, 10).map(|elves| elves.len()), Ok(4));
+        assert_eq!(
+            top_k_elves(&data, usize::MAX).map(|elves| elves.len()),
+            Ok(4)
+        );
+    }
+
+    #[test]
+    fn test_report() {
+        let data: Vec<String> = ["1000", "2000", "", "4000", "", "", "0", "6000", ""]
+            .map(String::from)
+            .to_vec();
+        let report = Inventory::parse(&data).unwrap().report().unwrap();
+        assert_eq!(report.totals, vec![3000, 4000, 0, 6000, 0]);
+        assert_eq!(report.item_counts, vec![2, 1, 0, 2, 0]);
+        assert_eq!(report.sum, 13000);
+        assert_eq!(report.median, 3000.0);
+        assert_eq!(report.percentiles[2], (50, 3000));
+        assert_eq!(report.histogram.iter().map(|b| b.count).sum::<usize>(), 5);
+        assert_eq!(
+            report.anomalies,
+            vec![
+                Anomaly::EmptyElf { index: 2, line: 6 },
+                Anomaly::ZeroCalorieItem { index: 3, line: 7 },
+                Anomaly::EmptyElf { index: 4, line: 9 },
+            ]
+        );
+    }
+}
@@ -0,0 +1,194 @@
+// Scores a strategy guide under every possible meaning of its second column: either the gesture
+// to play (6 ways to map X/Y/Z to Rock/Paper/Scissors) or the outcome to get (6 ways to map
+// X/Y/Z to Loss/Draw/Win).
+
+use std::cmp::Reverse;
+use std::fmt;
+
+use super::{parse_round, Gesture, HandGame, Outcome};
+use crate::{Error, Result};
+
+const PERMUTATIONS: [[usize; 3]; 6] = [
+    [0, 1, 2],
+    [0, 2, 1],
+    [1, 0, 2],
+    [1, 2, 0],
+    [2, 0, 1],
+    [2, 1, 0],
+];
+
+const OUTCOMES: [Outcome; 3] = [Outcome::Loss, Outcome::Draw, Outcome::Win];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Decoding {
+    // X, Y and Z are the gestures to play
+    Gestures([Gesture; 3]),
+    // X, Y and Z are the outcomes to get
+    Outcomes([Outcome; 3]),
+}
+
+impl Decoding {
+    pub fn all() -> Vec<Decoding> {
+        let gestures = PERMUTATIONS.iter().map(|&p| Decoding::Gestures(p));
+        let outcomes = PERMUTATIONS
+            .iter()
+            .map(|p| Decoding::Outcomes(p.map(|i| OUTCOMES[i])));
+        gestures.chain(outcomes).collect()
+    }
+
+    // Decodings used by part 1 and part 2 of the puzzle
+    pub fn part1() -> Decoding {
+        Decoding::Gestures([0, 1, 2])
+    }
+
+    pub fn part2() -> Decoding {
+        Decoding::Outcomes(OUTCOMES)
+    }
+
+    // Gesture the player picks against `opponent` for a second column letter (0 for X, etc.)
+    fn player(&self, game: &HandGame, opponent: Gesture, letter: usize) -> Gesture {
+        match self {
+            Decoding::Gestures(gestures) => gestures[letter],
+            Decoding::Outcomes(outcomes) => game
+                .needed_for(opponent, outcomes[letter])
+                .expect("Every outcome is possible"),
+        }
+    }
+}
+
+impl fmt::Display for Decoding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let game = HandGame::rock_paper_scissors();
+        for (letter, name) in ['X', 'Y', 'Z'].iter().enumerate() {
+            if letter > 0 {
+                write!(f, " ")?;
+            }
+            match self {
+                Decoding::Gestures(gestures) => {
+                    write!(f, "{}={}", name, game.name(gestures[letter]).unwrap_or("?"))?
+                }
+                Decoding::Outcomes(outcomes) => write!(f, "{}={:?}", name, outcomes[letter])?,
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Round {
+    pub line: usize, // Starting at 1
+    pub opponent: Gesture,
+    pub player: Gesture,
+    pub outcome: Outcome,
+    pub score: u32,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Evaluation {
+    pub decoding: Decoding,
+    pub total: u32,
+    pub rounds: Vec<Round>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Analysis {
+    pub evaluations: Vec<Evaluation>, // Highest total first
+}
+
+impl Analysis {
+    pub fn best(&self) -> &Evaluation {
+        &self.evaluations[0]
+    }
+
+    pub fn worst(&self) -> &Evaluation {
+        &self.evaluations[self.evaluations.len() - 1]
+    }
+
+    pub fn get(&self, decoding: Decoding) -> &Evaluation {
+        self.evaluations
+            .iter()
+            .find(|e| e.decoding == decoding)
+            .expect("Every decoding is evaluated")
+    }
+
+    // Decodings giving exactly `target` points
+    pub fn matching(&self, target: u32) -> Vec<&Evaluation> {
+        self.evaluations
+            .iter()
+            .filter(|e| e.total == target)
+            .collect()
+    }
+}
+
+pub fn evaluate(lines: &[String], decoding: Decoding) -> Result<Evaluation> {
+    let game = HandGame::rock_paper_scissors();
+    let mut total: u32 = 0;
+    let mut rounds = Vec::with_capacity(lines.len());
+    for (i, line) in lines.iter().enumerate() {
+        let (opp_pick, letter) = parse_round(i, line)?;
+        let opponent = opp_pick as Gesture - 'A' as Gesture;
+        let player = decoding.player(&game, opponent, letter as usize - 'X' as usize);
+        let score = game.score(player, opponent)?;
+        total = total.checked_add(score).ok_or(Error::Overflow)?;
+        rounds.push(Round {
+            line: i + 1,
+            opponent,
+            player,
+            outcome: game.outcome(player, opponent)?,
+            score,
+        });
+    }
+    Ok(Evaluation {
+        decoding,
+        total,
+        rounds,
+    })
+}
+
+pub fn analyze(lines: &[String]) -> Result<Analysis> {
+    let mut evaluations = Decoding::all()
+        .into_iter()
+        .map(|decoding| evaluate(lines, decoding))
+        .collect::<Result<Vec<_>>>()?;
+    // Stable sort, ties keep the order of `Decoding::all`
+    evaluations.sort_by_key(|e| Reverse(e.total));
+    Ok(Analysis { evaluations })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_analyze() {
+        let data = ["A Y", "B X", "C Z"].map(String::from).to_vec();
+        let analysis = analyze(&data).unwrap();
+        assert_eq!(analysis.evaluations.len(), 12);
+        assert_eq!(analysis.get(Decoding::part1()).total, 15);
+        assert_eq!(analysis.get(Decoding::part2()).total, 12);
+        assert!(analysis
+            .matching(15)
+            .iter()
+            .any(|e| e.decoding == Decoding::part1()));
+
+        // Always winning: 6 points per round plus the winning gestures (Paper, Scissors, Rock)
+        assert_eq!(analysis.best().total, 24);
+        assert_eq!(analysis.worst().total, 3 + 2 + 1);
+        assert_eq!(
+            analysis.best().rounds[1],
+            Round {
+                line: 2,
+                opponent: 1,
+                player: 2,
+                outcome: Outcome::Win,
+                score: 9,
+            }
+        );
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Decoding::part1().to_string(), "X=Rock Y=Paper Z=Scissors");
+        assert_eq!(Decoding::part2().to_string(), "X=Loss Y=Draw Z=Win");
+    }
+}
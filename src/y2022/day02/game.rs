@@ -0,0 +1,304 @@
+// Hand games like Rock-Paper-Scissors, described by a table of which gesture beats which. Pairs
+// where neither gesture beats the other (including a gesture against itself) are draws.
+
+use std::fmt;
+
+use crate::{Error, Result};
+
+pub type Gesture = usize;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+    Loss,
+    Draw,
+    Win,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RuleError {
+    UnknownGesture(Gesture),
+    // A gesture can't beat itself
+    SelfBeat(Gesture),
+    // Both gestures beat each other
+    Contradiction(Gesture, Gesture),
+    // Number of gesture weights doesn't match the number of gestures
+    ScoringSize { expected: usize, found: usize },
+}
+
+impl fmt::Display for RuleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RuleError::UnknownGesture(gesture) => write!(f, "Unknown gesture {}", gesture),
+            RuleError::SelfBeat(gesture) => write!(f, "Gesture {} beats itself", gesture),
+            RuleError::Contradiction(a, b) => {
+                write!(f, "Gestures {} and {} beat each other", a, b)
+            }
+            RuleError::ScoringSize { expected, found } => {
+                write!(f, "Expected {} gesture weights, found {}", expected, found)
+            }
+        }
+    }
+}
+
+impl std::error::Error for RuleError {}
+
+// Gestures are plain indices, so an engine user can pass any of them
+impl From<RuleError> for Error {
+    fn from(error: RuleError) -> Self {
+        Error::InvalidArgument(error.to_string())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Scoring {
+    pub loss: u32,
+    pub draw: u32,
+    pub win: u32,
+    pub gestures: Vec<u32>, // Points for playing each gesture
+}
+
+impl Scoring {
+    // Scoring used by the puzzle: 0/3/6 for the outcome, plus 1 for the first gesture, 2 for the
+    // second, etc.
+    pub fn standard(gestures: usize) -> Self {
+        Scoring {
+            loss: 0,
+            draw: 3,
+            win: 6,
+            gestures: (1..=gestures as u32).collect(),
+        }
+    }
+
+    pub fn outcome(&self, outcome: Outcome) -> u32 {
+        match outcome {
+            Outcome::Loss => self.loss,
+            Outcome::Draw => self.draw,
+            Outcome::Win => self.win,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HandGame {
+    names: Vec<String>,
+    beats: Vec<Vec<bool>>, // beats[a][b] is true if a beats b
+    scoring: Scoring,
+}
+
+impl HandGame {
+    // `beats` lists (winner, loser) pairs of gesture indices
+    pub fn from_rules(
+        names: &[&str],
+        beats: &[(Gesture, Gesture)],
+    ) -> std::result::Result<Self, RuleError> {
+        let n = names.len();
+        let mut table = vec![vec![false; n]; n];
+        for &(winner, loser) in beats {
+            if winner >= n || loser >= n {
+                return Err(RuleError::UnknownGesture(winner.max(loser)));
+            }
+            if winner == loser {
+                return Err(RuleError::SelfBeat(winner));
+            }
+            if table[loser][winner] {
+                return Err(RuleError::Contradiction(winner, loser));
+            }
+            table[winner][loser] = true;
+        }
+
+        Ok(HandGame {
+            names: names.iter().map(|&name| String::from(name)).collect(),
+            beats: table,
+            scoring: Scoring::standard(n),
+        })
+    }
+
+    // Balanced cyclic game of `n` gestures (n odd, at least 3), where every gesture beats half
+    // of the others: gesture i beats i-1, i-3, i-5... (modulo n)
+    pub fn cyclic(n: usize) -> Option<Self> {
+        if n < 3 || n.is_multiple_of(2) {
+            return None;
+        }
+        let names: Vec<String> = (1..=n).map(|i| format!("Gesture {}", i)).collect();
+        let names: Vec<&str> = names.iter().map(String::as_str).collect();
+        let beats: Vec<(Gesture, Gesture)> = (0..n)
+            .flat_map(|i| (1..n).step_by(2).map(move |d| (i, (i + n - d) % n)))
+            .collect();
+        HandGame::from_rules(&names, &beats).ok()
+    }
+
+    pub fn rock_paper_scissors() -> Self {
+        HandGame::from_rules(&["Rock", "Paper", "Scissors"], &[(0, 2), (1, 0), (2, 1)])
+            .expect("Valid rules")
+    }
+
+    pub fn rock_paper_scissors_lizard_spock() -> Self {
+        HandGame::from_rules(
+            &["Rock", "Paper", "Scissors", "Lizard", "Spock"],
+            &[
+                (2, 1), // Scissors cuts Paper
+                (1, 0), // Paper covers Rock
+                (0, 3), // Rock crushes Lizard
+                (3, 4), // Lizard poisons Spock
+                (4, 2), // Spock smashes Scissors
+                (2, 3), // Scissors decapitates Lizard
+                (3, 1), // Lizard eats Paper
+                (1, 4), // Paper disproves Spock
+                (4, 0), // Spock vaporizes Rock
+                (0, 2), // Rock crushes Scissors
+            ],
+        )
+        .expect("Valid rules")
+    }
+
+    pub fn with_scoring(mut self, scoring: Scoring) -> std::result::Result<Self, RuleError> {
+        if scoring.gestures.len() != self.len() {
+            return Err(RuleError::ScoringSize {
+                expected: self.len(),
+                found: scoring.gestures.len(),
+            });
+        }
+        self.scoring = scoring;
+        Ok(self)
+    }
+
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+
+    fn check(&self, gesture: Gesture) -> std::result::Result<Gesture, RuleError> {
+        if gesture < self.len() {
+            Ok(gesture)
+        } else {
+            Err(RuleError::UnknownGesture(gesture))
+        }
+    }
+
+    pub fn name(&self, gesture: Gesture) -> std::result::Result<&str, RuleError> {
+        Ok(&self.names[self.check(gesture)?])
+    }
+
+    pub fn scoring(&self) -> &Scoring {
+        &self.scoring
+    }
+
+    pub fn outcome(
+        &self,
+        player: Gesture,
+        opponent: Gesture,
+    ) -> std::result::Result<Outcome, RuleError> {
+        let (player, opponent) = (self.check(player)?, self.check(opponent)?);
+        Ok(if self.beats[player][opponent] {
+            Outcome::Win
+        } else if self.beats[opponent][player] {
+            Outcome::Loss
+        } else {
+            Outcome::Draw
+        })
+    }
+
+    // Points for the player: gesture weight plus outcome weight, which custom scorings can make
+    // overflow
+    pub fn score(&self, player: Gesture, opponent: Gesture) -> Result<u32> {
+        let outcome = self.outcome(player, opponent)?;
+        self.scoring.gestures[player]
+            .checked_add(self.scoring.outcome(outcome))
+            .ok_or(Error::Overflow)
+    }
+
+    // Every gesture the player can pick to get `outcome` against `opponent`, none if it is unknown
+    pub fn picks_for(&self, opponent: Gesture, outcome: Outcome) -> Vec<Gesture> {
+        (0..self.len())
+            .filter(|&player| self.outcome(player, opponent) == Ok(outcome))
+            .collect()
+    }
+
+    // First gesture giving `outcome` against `opponent`, unique for Rock-Paper-Scissors
+    pub fn needed_for(&self, opponent: Gesture, outcome: Outcome) -> Option<Gesture> {
+        (0..self.len()).find(|&player| self.outcome(player, opponent) == Ok(outcome))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rock_paper_scissors() {
+        let game = HandGame::rock_paper_scissors();
+        let cyclic = HandGame::cyclic(3).unwrap();
+        for (a, b) in (0..3).flat_map(|a| (0..3).map(move |b| (a, b))) {
+            assert_eq!(game.outcome(a, b), cyclic.outcome(a, b));
+        }
+        assert_eq!(game.outcome(0, 2), Ok(Outcome::Win));
+        assert_eq!(game.outcome(0, 1), Ok(Outcome::Loss));
+        assert_eq!(game.outcome(0, 3), Err(RuleError::UnknownGesture(3)));
+        assert_eq!(game.name(1), Ok("Paper"));
+        assert_eq!(
+            game.score(5, 0),
+            Err(Error::InvalidArgument(String::from("Unknown gesture 5")))
+        );
+        assert_eq!(game.score(1, 0), Ok(8));
+
+        let scoring = Scoring {
+            win: u32::MAX,
+            ..Scoring::standard(3)
+        };
+        let game = game.with_scoring(scoring).unwrap();
+        assert_eq!(game.score(0, 1), Ok(1));
+        assert_eq!(game.score(0, 2), Err(Error::Overflow));
+        assert_eq!(game.needed_for(0, Outcome::Loss), Some(2));
+        assert_eq!(game.needed_for(2, Outcome::Draw), Some(2));
+    }
+
+    #[test]
+    fn test_cyclic_games_are_balanced() {
+        assert!(HandGame::cyclic(4).is_none());
+        for game in [
+            HandGame::cyclic(7).unwrap(),
+            HandGame::rock_paper_scissors_lizard_spock(),
+        ] {
+            for opponent in 0..game.len() {
+                let half = (game.len() - 1) / 2;
+                assert_eq!(game.picks_for(opponent, Outcome::Win).len(), half);
+                assert_eq!(game.picks_for(opponent, Outcome::Loss).len(), half);
+                assert_eq!(game.picks_for(opponent, Outcome::Draw), vec![opponent]);
+            }
+        }
+    }
+
+    #[test]
+    fn test_invalid_rules() {
+        let names = ["A", "B"];
+        assert_eq!(
+            HandGame::from_rules(&names, &[(0, 0)]),
+            Err(RuleError::SelfBeat(0))
+        );
+        assert_eq!(
+            HandGame::from_rules(&names, &[(0, 1), (1, 0)]),
+            Err(RuleError::Contradiction(1, 0))
+        );
+        assert_eq!(
+            RuleError::Contradiction(1, 0).to_string(),
+            "Gestures 1 and 0 beat each other"
+        );
+        assert_eq!(
+            HandGame::from_rules(&names, &[(0, 2)]),
+            Err(RuleError::UnknownGesture(2))
+        );
+        let scoring = Scoring::standard(3);
+        assert_eq!(
+            HandGame::from_rules(&names, &[])
+                .unwrap()
+                .with_scoring(scoring),
+            Err(RuleError::ScoringSize {
+                expected: 2,
+                found: 3
+            })
+        );
+    }
+}
@@ -0,0 +1,87 @@
+pub mod analyze;
+mod game;
+pub mod tournament;
+
+pub use game::{Gesture, HandGame, Outcome, RuleError, Scoring};
+
+use crate::{explain, Error, Result};
+
+fn parse_round(index: usize, line: &str) -> Result<(char, char)> {
+    // Line should have format: opponent pick, space, second column (X, Y or Z)
+    let mut chars = line.chars();
+    match (chars.next(), chars.next(), chars.next(), chars.next()) {
+        (Some(opp @ 'A'..='C'), Some(' '), Some(second @ 'X'..='Z'), None) => Ok((opp, second)),
+        _ => Err(Error::invalid_line(index, "expected `<A|B|C> <X|Y|Z>`")),
+    }
+}
+
+pub fn part1(lines: Vec<String>) -> Result<u32> {
+    // Both columns are picks: A/X = Rock, B/Y = Paper, C/Z = Scissors
+    let game = HandGame::rock_paper_scissors();
+    let mut score = 0;
+    for (i, line) in lines.iter().enumerate() {
+        let (opp_pick, player_pick) = parse_round(i, line)?;
+        let opponent = opp_pick as Gesture - 'A' as Gesture;
+        let player = player_pick as Gesture - 'X' as Gesture;
+
+        let round_score = game.score(player, opponent)?;
+        score += round_score;
+
+        explain!(
+            "round",
+            round = i + 1,
+            opponent = game.name(opponent)?,
+            player = game.name(player)?,
+            score = round_score,
+        );
+    }
+    Ok(score)
+}
+
+pub fn part2(lines: Vec<String>) -> Result<u32> {
+    // Second column is the expected outcome: X = lose, Y = draw, Z = win
+    let game = HandGame::rock_paper_scissors();
+    let mut score = 0;
+    for (i, line) in lines.iter().enumerate() {
+        let (opp_pick, expected_res) = parse_round(i, line)?;
+        let opponent = opp_pick as Gesture - 'A' as Gesture;
+        let outcome = match expected_res {
+            'X' => Outcome::Loss,
+            'Y' => Outcome::Draw,
+            _ => Outcome::Win,
+        };
+
+        // There is always exactly one pick for each outcome in Rock-Paper-Scissors
+        let player = game
+            .needed_for(opponent, outcome)
+            .expect("Every outcome is possible");
+        let round_score = game.score(player, opponent)?;
+        score += round_score;
+
+        explain!(
+            "round",
+            round = i + 1,
+            opponent = game.name(opponent)?,
+            player = game.name(player)?,
+            score = round_score,
+        );
+    }
+    Ok(score)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        let data = ["A Y", "B X", "C Z"].map(String::from).to_vec();
+        assert_eq!(part1(data), Ok(15));
+    }
+
+    #[test]
+    fn test_part2() {
+        let data = ["A Y", "B X", "C Z"].map(String::from).to_vec();
+        assert_eq!(part2(data), Ok(12));
+    }
+}
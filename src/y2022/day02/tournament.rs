@@ -0,0 +1,247 @@
+// Long matches between two strategies, scored like part 1. Every source of randomness comes
+// from a seeded generator, so a match can be replayed exactly.
+
+use super::{parse_round, Gesture, HandGame, Outcome};
+use crate::rng::Rng;
+use crate::{Error, Result};
+
+pub trait Strategy {
+    fn name(&self) -> &str;
+
+    fn pick(&mut self, game: &HandGame, rng: &mut Rng) -> Gesture;
+
+    // Called after every round with both picks, from this strategy's point of view
+    fn observe(&mut self, _own: Gesture, _opponent: Gesture, _outcome: Outcome) {}
+}
+
+// Plays one column of a strategy guide in order, starting over once it runs out
+pub struct Guide {
+    picks: Vec<Gesture>,
+    next: usize,
+}
+
+impl Guide {
+    // The second column read as gestures (X = Rock, Y = Paper, Z = Scissors), like part 1
+    pub fn player(lines: &[String]) -> Result<Self> {
+        Guide::from_column(lines, |(_, player)| player as Gesture - 'X' as Gesture)
+    }
+
+    // The opponent's column (A = Rock, B = Paper, C = Scissors)
+    pub fn opponent(lines: &[String]) -> Result<Self> {
+        Guide::from_column(lines, |(opponent, _)| opponent as Gesture - 'A' as Gesture)
+    }
+
+    fn from_column(lines: &[String], column: impl Fn((char, char)) -> Gesture) -> Result<Self> {
+        if lines.is_empty() {
+            return Err(Error::EmptyInput);
+        }
+        let picks = lines
+            .iter()
+            .enumerate()
+            .map(|(i, line)| parse_round(i, line).map(&column))
+            .collect::<Result<_>>()?;
+        Ok(Guide { picks, next: 0 })
+    }
+}
+
+impl Strategy for Guide {
+    fn name(&self) -> &str {
+        "guide"
+    }
+
+    fn pick(&mut self, _game: &HandGame, _rng: &mut Rng) -> Gesture {
+        let pick = self.picks[self.next];
+        self.next = (self.next + 1) % self.picks.len();
+        pick
+    }
+}
+
+pub struct UniformRandom;
+
+impl Strategy for UniformRandom {
+    fn name(&self) -> &str {
+        "uniform random"
+    }
+
+    fn pick(&mut self, game: &HandGame, rng: &mut Rng) -> Gesture {
+        rng.below(game.len())
+    }
+}
+
+// Counts the opponent's gestures and plays against the most frequent one (random until the
+// opponent played once)
+#[derive(Default)]
+pub struct FrequencyCounter {
+    counts: Vec<usize>,
+}
+
+impl Strategy for FrequencyCounter {
+    fn name(&self) -> &str {
+        "frequency counter"
+    }
+
+    fn pick(&mut self, game: &HandGame, rng: &mut Rng) -> Gesture {
+        // First most frequent gesture on ties
+        let most_frequent = (0..self.counts.len()).rev().max_by_key(|&g| self.counts[g]);
+        let winning = most_frequent.map(|g| game.picks_for(g, Outcome::Win));
+        match winning {
+            Some(picks) if !picks.is_empty() => picks[rng.below(picks.len())],
+            _ => rng.below(game.len()),
+        }
+    }
+
+    fn observe(&mut self, _own: Gesture, opponent: Gesture, _outcome: Outcome) {
+        if self.counts.len() <= opponent {
+            self.counts.resize(opponent + 1, 0);
+        }
+        self.counts[opponent] += 1;
+    }
+}
+
+// Keeps its gesture after a win, otherwise moves on to the next gesture
+#[derive(Default)]
+pub struct WinStayLoseShift {
+    last: Option<(Gesture, Outcome)>,
+}
+
+impl Strategy for WinStayLoseShift {
+    fn name(&self) -> &str {
+        "win-stay lose-shift"
+    }
+
+    fn pick(&mut self, game: &HandGame, rng: &mut Rng) -> Gesture {
+        match self.last {
+            Some((own, Outcome::Win)) => own,
+            Some((own, _)) => (own + 1) % game.len(),
+            None => rng.below(game.len()),
+        }
+    }
+
+    fn observe(&mut self, own: Gesture, _opponent: Gesture, outcome: Outcome) {
+        self.last = Some((own, outcome));
+    }
+}
+
+// Results of a match, from the first strategy's point of view
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct MatchReport {
+    pub rounds: usize,
+    pub wins: usize,
+    pub draws: usize,
+    pub losses: usize,
+    pub scores: (u64, u64),
+}
+
+impl MatchReport {
+    pub fn win_rate(&self) -> f64 {
+        self.rate(self.wins)
+    }
+
+    pub fn draw_rate(&self) -> f64 {
+        self.rate(self.draws)
+    }
+
+    pub fn loss_rate(&self) -> f64 {
+        self.rate(self.losses)
+    }
+
+    fn rate(&self, count: usize) -> f64 {
+        if self.rounds == 0 {
+            0.0
+        } else {
+            count as f64 / self.rounds as f64
+        }
+    }
+}
+
+pub struct Tournament {
+    game: HandGame,
+    seed: u64,
+}
+
+impl Tournament {
+    pub fn new(game: HandGame, seed: u64) -> Self {
+        Tournament { game, seed }
+    }
+
+    pub fn play(
+        &self,
+        a: &mut dyn Strategy,
+        b: &mut dyn Strategy,
+        rounds: usize,
+    ) -> Result<MatchReport> {
+        let mut rng = Rng::new(self.seed);
+        let mut report = MatchReport {
+            rounds,
+            ..Default::default()
+        };
+        for _ in 0..rounds {
+            let pick_a = a.pick(&self.game, &mut rng);
+            let pick_b = b.pick(&self.game, &mut rng);
+            let outcome = self.game.outcome(pick_a, pick_b)?;
+            match outcome {
+                Outcome::Win => report.wins += 1,
+                Outcome::Draw => report.draws += 1,
+                Outcome::Loss => report.losses += 1,
+            }
+            report.scores.0 += self.game.score(pick_a, pick_b)? as u64;
+            report.scores.1 += self.game.score(pick_b, pick_a)? as u64;
+
+            a.observe(pick_a, pick_b, outcome);
+            b.observe(pick_b, pick_a, self.game.outcome(pick_b, pick_a)?);
+        }
+        Ok(report)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::y2022::day02::part1;
+
+    #[test]
+    fn test_guide_match_is_part1() {
+        let data = ["A Y", "B X", "C Z"].map(String::from).to_vec();
+        let tournament = Tournament::new(HandGame::rock_paper_scissors(), 0);
+        let mut player = Guide::player(&data).unwrap();
+        let mut opponent = Guide::opponent(&data).unwrap();
+        let report = tournament.play(&mut player, &mut opponent, 3).unwrap();
+        assert_eq!(report.scores.0, part1(data).unwrap() as u64);
+        assert_eq!((report.wins, report.draws, report.losses), (1, 1, 1));
+    }
+
+    #[test]
+    fn test_seeded_matches() {
+        let tournament = Tournament::new(HandGame::rock_paper_scissors(), 42);
+        let play = || {
+            tournament
+                .play(&mut UniformRandom, &mut WinStayLoseShift::default(), 1000)
+                .unwrap()
+        };
+        assert_eq!(play(), play());
+
+        // Always playing Rock is beaten by the frequency counter from the second round on
+        let rock = [String::from("A X")];
+        let mut counter = FrequencyCounter::default();
+        let report = tournament
+            .play(&mut counter, &mut Guide::player(&rock).unwrap(), 1000)
+            .unwrap();
+        assert!(report.wins >= 999);
+        assert!(report.win_rate() > 0.99);
+
+        // A strategy picking a gesture the game doesn't have is an error, not a panic
+        struct Cheater;
+        impl Strategy for Cheater {
+            fn name(&self) -> &str {
+                "cheater"
+            }
+
+            fn pick(&mut self, _game: &HandGame, _rng: &mut Rng) -> Gesture {
+                7
+            }
+        }
+        assert!(tournament
+            .play(&mut Cheater, &mut UniformRandom, 1)
+            .is_err());
+    }
+}
@@ -0,0 +1,195 @@
+// Lenient pass over a list of rucksacks that reports what part 1 and part 2 would reject or
+// silently ignore: lines without exactly one misplaced item type, compartments of different
+// sizes, unknown items, and groups without exactly one badge candidate.
+
+use std::fmt;
+
+use super::ItemSet;
+use crate::{Error, Result};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LineIssue {
+    NoCommonItem,
+    SeveralCommonItems(usize),
+    OddLength(usize),
+    // Characters outside of a-z and A-Z, in order of appearance
+    InvalidItems(Vec<char>),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GroupIssue {
+    NoBadge,
+    SeveralBadges(usize),
+    // Last group of the input, with less elves than the group size
+    Incomplete(usize),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LineAudit {
+    pub line: usize, // Starting at 1
+    pub common: ItemSet,
+    pub issues: Vec<LineIssue>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GroupAudit {
+    pub group: usize,      // Starting at 1
+    pub first_line: usize, // Starting at 1
+    pub candidates: ItemSet,
+    pub issues: Vec<GroupIssue>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Audit {
+    pub lines: Vec<LineAudit>,
+    pub groups: Vec<GroupAudit>,
+}
+
+impl Audit {
+    pub fn is_clean(&self) -> bool {
+        self.lines.iter().all(|l| l.issues.is_empty())
+            && self.groups.iter().all(|g| g.issues.is_empty())
+    }
+}
+
+impl fmt::Display for LineIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LineIssue::NoCommonItem => write!(f, "no item type in both compartments"),
+            LineIssue::SeveralCommonItems(n) => {
+                write!(f, "{} item types in both compartments", n)
+            }
+            LineIssue::OddLength(n) => write!(f, "odd number of items ({})", n),
+            LineIssue::InvalidItems(items) => write!(f, "invalid items {:?}", items),
+        }
+    }
+}
+
+impl fmt::Display for GroupIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GroupIssue::NoBadge => write!(f, "no badge candidate"),
+            GroupIssue::SeveralBadges(n) => write!(f, "{} badge candidates", n),
+            GroupIssue::Incomplete(n) => write!(f, "incomplete group of {} elves", n),
+        }
+    }
+}
+
+// Item types of `items`, ignoring invalid ones
+fn valid_items(items: &[char]) -> ItemSet {
+    let valid: String = items.iter().filter(|c| c.is_ascii_alphabetic()).collect();
+    ItemSet::from_items(&valid).expect("Only letters are kept")
+}
+
+fn audit_line(index: usize, line: &str) -> LineAudit {
+    let items: Vec<char> = line.chars().collect();
+    let mut issues = Vec::new();
+    if items.len() % 2 == 1 {
+        issues.push(LineIssue::OddLength(items.len()));
+    }
+    let invalid: Vec<char> = items
+        .iter()
+        .copied()
+        .filter(|c| !c.is_ascii_alphabetic())
+        .collect();
+    if !invalid.is_empty() {
+        issues.push(LineIssue::InvalidItems(invalid));
+    }
+
+    // With an odd number of items, the middle one goes to the second compartment
+    let (h1, h2) = items.split_at(items.len() / 2);
+    let common = valid_items(h1) & valid_items(h2);
+    match common.len() {
+        0 => issues.push(LineIssue::NoCommonItem),
+        1 => (),
+        n => issues.push(LineIssue::SeveralCommonItems(n)),
+    }
+    LineAudit {
+        line: index + 1,
+        common,
+        issues,
+    }
+}
+
+pub fn audit(lines: &[String], group_size: usize) -> Result<Audit> {
+    if group_size == 0 {
+        return Err(Error::NoSolution(String::from("groups can't be empty")));
+    }
+    let line_audits = lines
+        .iter()
+        .enumerate()
+        .map(|(i, line)| audit_line(i, line))
+        .collect();
+
+    let mut groups = Vec::new();
+    for (i, group) in lines.chunks(group_size).enumerate() {
+        let candidates = group.iter().fold(ItemSet::ALL, |acc, line| {
+            acc & valid_items(&line.chars().collect::<Vec<_>>())
+        });
+        let mut issues = Vec::new();
+        if group.len() < group_size {
+            issues.push(GroupIssue::Incomplete(group.len()));
+        }
+        match candidates.len() {
+            0 => issues.push(GroupIssue::NoBadge),
+            1 => (),
+            n => issues.push(GroupIssue::SeveralBadges(n)),
+        }
+        groups.push(GroupAudit {
+            group: i + 1,
+            first_line: i * group_size + 1,
+            candidates,
+            issues,
+        });
+    }
+
+    Ok(Audit {
+        lines: line_audits,
+        groups,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_audit() {
+        let data = [
+            "vJrwpWtwJgWrhcsFMMfFFhFp",
+            "jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL",
+            "PmmdzqPrVvPwwTWBwg",
+        ]
+        .map(String::from)
+        .to_vec();
+        let report = audit(&data, 3).unwrap();
+        assert!(report.is_clean());
+        assert_eq!(report.lines[1].common.to_string(), "L");
+        assert_eq!(report.groups[0].candidates.to_string(), "r");
+
+        let data = ["abab", "abc", "a1b2", "xyz"].map(String::from).to_vec();
+        let report = audit(&data, 3).unwrap();
+        assert_eq!(report.lines[0].issues, [LineIssue::SeveralCommonItems(2)]);
+        assert_eq!(
+            report.lines[1].issues,
+            [LineIssue::OddLength(3), LineIssue::NoCommonItem]
+        );
+        assert_eq!(
+            report.lines[2].issues,
+            [
+                LineIssue::InvalidItems(vec!['1', '2']),
+                LineIssue::NoCommonItem
+            ]
+        );
+        assert_eq!(report.groups[0].issues, [GroupIssue::SeveralBadges(2)]);
+        assert_eq!(
+            report.groups[1].issues,
+            [GroupIssue::Incomplete(1), GroupIssue::SeveralBadges(3)]
+        );
+        assert_eq!(report.groups[1].first_line, 4);
+        assert_eq!(
+            audit(&data, 0),
+            Err(Error::NoSolution(String::from("groups can't be empty")))
+        );
+    }
+}
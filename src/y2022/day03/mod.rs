@@ -0,0 +1,262 @@
+pub mod audit;
+
+use std::fmt;
+use std::ops::{BitAnd, BitOr};
+
+use crate::{explain, Error, Result};
+
+// Set of item types, bit `p - 1` is set for the item of priority `p`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ItemSet(u64);
+
+impl ItemSet {
+    pub const EMPTY: ItemSet = ItemSet(0);
+    pub const ALL: ItemSet = ItemSet((1 << 52) - 1);
+
+    // Fails with the first item that isn't in a-z or A-Z
+    pub fn from_items(items: &str) -> std::result::Result<Self, char> {
+        items
+            .chars()
+            .try_fold(ItemSet::EMPTY, |set, c| match priority(c) {
+                Some(p) => Ok(set.with(p)),
+                None => Err(c),
+            })
+    }
+
+    fn with(self, priority: u32) -> Self {
+        ItemSet(self.0 | 1 << (priority - 1))
+    }
+
+    pub fn contains(&self, item: char) -> bool {
+        priority(item).is_some_and(|p| self.0 & 1 << (p - 1) != 0)
+    }
+
+    pub fn union(self, other: ItemSet) -> ItemSet {
+        ItemSet(self.0 | other.0)
+    }
+
+    pub fn intersection(self, other: ItemSet) -> ItemSet {
+        ItemSet(self.0 & other.0)
+    }
+
+    pub fn difference(self, other: ItemSet) -> ItemSet {
+        ItemSet(self.0 & !other.0)
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    // Lowest priority in the set
+    pub fn first(&self) -> Option<u32> {
+        (!self.is_empty()).then(|| self.0.trailing_zeros() + 1)
+    }
+
+    // Priorities in increasing order
+    pub fn priorities(self) -> impl Iterator<Item = u32> {
+        (1..=52).filter(move |p| self.0 & 1 << (p - 1) != 0)
+    }
+
+    pub fn items(self) -> impl Iterator<Item = char> {
+        self.priorities().map(item)
+    }
+}
+
+impl BitAnd for ItemSet {
+    type Output = ItemSet;
+
+    fn bitand(self, other: ItemSet) -> ItemSet {
+        self.intersection(other)
+    }
+}
+
+impl BitOr for ItemSet {
+    type Output = ItemSet;
+
+    fn bitor(self, other: ItemSet) -> ItemSet {
+        self.union(other)
+    }
+}
+
+impl fmt::Display for ItemSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.items().try_for_each(|c| write!(f, "{}", c))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rucksack {
+    pub compartments: [ItemSet; 2],
+}
+
+impl Rucksack {
+    pub fn parse(index: usize, line: &str) -> Result<Self> {
+        // Items are checked first, so that the line is only split between ASCII letters
+        ItemSet::from_items(line)
+            .map_err(|c| Error::invalid_line(index, format!("invalid item {:?}", c)))?;
+        if !line.len().is_multiple_of(2) {
+            return Err(Error::invalid_line(
+                index,
+                "compartments have different sizes",
+            ));
+        }
+        let (h1, h2) = line.split_at(line.len() / 2);
+        let compartment = |items| ItemSet::from_items(items).expect("Items were checked");
+        Ok(Rucksack {
+            compartments: [compartment(h1), compartment(h2)],
+        })
+    }
+
+    pub fn items(&self) -> ItemSet {
+        self.compartments[0] | self.compartments[1]
+    }
+
+    // Item types present in both compartments
+    pub fn common(&self) -> ItemSet {
+        self.compartments[0] & self.compartments[1]
+    }
+}
+
+// Convert char to priority (a-z=1-26, A-Z=27-52)
+pub fn priority(c: char) -> Option<u32> {
+    match c {
+        'a'..='z' => Some(c as u32 - 'a' as u32 + 1),
+        'A'..='Z' => Some(c as u32 - 'A' as u32 + 27),
+        _ => None,
+    }
+}
+
+// Item of a priority in 1..=52
+pub fn item(priority: u32) -> char {
+    match priority {
+        1..=26 => (b'a' + (priority - 1) as u8) as char,
+        _ => (b'A' + (priority - 27) as u8) as char,
+    }
+}
+
+pub fn parse_rucksacks(lines: &[String]) -> Result<Vec<Rucksack>> {
+    lines
+        .iter()
+        .enumerate()
+        .map(|(i, line)| Rucksack::parse(i, line))
+        .collect()
+}
+
+// Item types carried by every elf of each group of `group_size` consecutive elves
+pub fn badges(lines: &[String], group_size: usize) -> Result<Vec<ItemSet>> {
+    if group_size == 0 {
+        return Err(Error::NoSolution(String::from("groups can't be empty")));
+    }
+    let mut items = Vec::with_capacity(lines.len());
+    for (i, line) in lines.iter().enumerate() {
+        let set = ItemSet::from_items(line)
+            .map_err(|c| Error::invalid_line(i, format!("invalid item {:?}", c)))?;
+        items.push(set);
+    }
+    if !lines.len().is_multiple_of(group_size) {
+        return Err(Error::invalid_line(
+            lines.len() - 1,
+            format!(
+                "last group has {} elves instead of {}",
+                lines.len() % group_size,
+                group_size
+            ),
+        ));
+    }
+    Ok(items
+        .chunks(group_size)
+        .map(|group| group.iter().fold(ItemSet::ALL, |acc, &set| acc & set))
+        .collect())
+}
+
+pub fn part1(lines: Vec<String>) -> Result<u32> {
+    // Find the item type present in both compartments of every rucksack
+    let mut score = 0;
+    for (i, rucksack) in parse_rucksacks(&lines)?.iter().enumerate() {
+        // As per definition of problem, only 1 item type can be in both compartments
+        if let Some(p) = rucksack.common().first() {
+            explain!("common_item", line = i + 1, item = item(p), priority = p);
+            score += p;
+        }
+    }
+    Ok(score)
+}
+
+pub fn part2(lines: Vec<String>) -> Result<u32> {
+    // Find the item type carried by the 3 elves of every group
+    let mut score = 0;
+    for (group_idx, badge) in badges(&lines, 3)?.iter().enumerate() {
+        if let Some(p) = badge.first() {
+            explain!("badge", group = group_idx + 1, item = item(p), priority = p);
+            score += p;
+        }
+    }
+    Ok(score)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        let data = [
+            "vJrwpWtwJgWrhcsFMMfFFhFp",
+            "jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL",
+            "PmmdzqPrVvPwwTWBwg",
+            "wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn",
+            "ttgJtRGJQctTZtZT",
+            "CrZsJsPPZsGzwwsLwLmpwMDw",
+        ]
+        .map(String::from)
+        .to_vec();
+        assert_eq!(part1(data), Ok(157));
+    }
+
+    #[test]
+    fn test_part2() {
+        let data = [
+            "vJrwpWtwJgWrhcsFMMfFFhFp",
+            "jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL",
+            "PmmdzqPrVvPwwTWBwg",
+            "wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn",
+            "ttgJtRGJQctTZtZT",
+            "CrZsJsPPZsGzwwsLwLmpwMDw",
+        ]
+        .map(String::from)
+        .to_vec();
+        assert_eq!(part2(data), Ok(70));
+    }
+
+    #[test]
+    fn test_item_sets() {
+        let rucksack = Rucksack::parse(0, "vJrwpWtwJgWrhcsFMMfFFhFp").unwrap();
+        assert_eq!(rucksack.common().to_string(), "p");
+        assert_eq!(rucksack.compartments[0].to_string(), "gprtvwJW");
+        assert_eq!(rucksack.items().len(), 14);
+        assert!(rucksack.compartments[1].contains('M'));
+        assert_eq!(
+            ItemSet::from_items("aZ")
+                .unwrap()
+                .priorities()
+                .collect::<Vec<_>>(),
+            [1, 52]
+        );
+        assert_eq!(ItemSet::from_items("ab1"), Err('1'));
+
+        let lines = ["abc", "bcd", "cde", "cx"].map(String::from).to_vec();
+        assert_eq!(
+            badges(&lines, 2).unwrap(),
+            [
+                ItemSet::from_items("bc").unwrap(),
+                ItemSet::from_items("c").unwrap()
+            ]
+        );
+        assert!(badges(&lines, 3).is_err());
+        assert!(badges(&lines, 0).is_err());
+    }
+}
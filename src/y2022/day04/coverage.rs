@@ -0,0 +1,174 @@
+// Coverage of the sections by every elf of the file, computed with a sweep line over the start
+// and end of each assignment so it only depends on the number of elves, not on section numbers.
+
+use super::parse_pair;
+use crate::Result;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Elf {
+    pub index: usize, // Starting at 0, the two elves of a line are next to each other
+    pub line: usize,  // Starting at 1
+    pub start: u32,
+    pub end: u32, // Inclusive
+}
+
+// Run of consecutive sections assigned to the same number of elves
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Segment {
+    pub start: u32,
+    pub end: u32, // Inclusive
+    pub count: usize,
+}
+
+impl Segment {
+    pub fn sections(&self) -> u64 {
+        (self.end - self.start) as u64 + 1
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Coverage {
+    pub elves: Vec<Elf>,
+    // Sections assigned to at least one elf, in increasing order. Sections between two
+    // segments aren't assigned to anyone.
+    pub segments: Vec<Segment>,
+}
+
+impl Coverage {
+    pub fn new(elves: Vec<Elf>) -> Self {
+        // Ends are stored one past the range, as u64 so that it can't overflow
+        let mut events: Vec<(u64, isize)> = elves
+            .iter()
+            .flat_map(|elf| [(elf.start as u64, 1), (elf.end as u64 + 1, -1)])
+            .collect();
+        events.sort_unstable();
+
+        let mut segments: Vec<Segment> = Vec::new();
+        let mut count = 0_isize;
+        let mut i = 0;
+        while i < events.len() {
+            let position = events[i].0;
+            while i < events.len() && events[i].0 == position {
+                count += events[i].1;
+                i += 1;
+            }
+            if count > 0 {
+                // Runs until the next event, there is always one while elves are counted
+                let end = events[i].0 - 1;
+                segments.push(Segment {
+                    start: position as u32,
+                    end: end as u32,
+                    count: count as usize,
+                });
+            }
+        }
+        Coverage { elves, segments }
+    }
+
+    pub fn from_lines(lines: &[String]) -> Result<Self> {
+        let mut elves = Vec::with_capacity(lines.len() * 2);
+        for (i, line) in lines.iter().enumerate() {
+            let (r1, r2) = parse_pair(i, line)?;
+            for (start, end) in [r1, r2] {
+                elves.push(Elf {
+                    index: elves.len(),
+                    line: i + 1,
+                    start,
+                    end,
+                });
+            }
+        }
+        Ok(Coverage::new(elves))
+    }
+
+    // Number of sections assigned to at least one elf
+    pub fn covered(&self) -> u64 {
+        self.segments.iter().map(Segment::sections).sum()
+    }
+
+    // Ranges of sections no one is assigned to, between the first and last assigned sections
+    pub fn gaps(&self) -> Vec<(u32, u32)> {
+        self.segments
+            .windows(2)
+            .filter(|w| w[0].end + 1 < w[1].start)
+            .map(|w| (w[0].end + 1, w[1].start - 1))
+            .collect()
+    }
+
+    pub fn max_elves(&self) -> usize {
+        self.segments.iter().map(|s| s.count).max().unwrap_or(0)
+    }
+
+    pub fn count_at(&self, section: u32) -> usize {
+        let i = self.segments.partition_point(|s| s.end < section);
+        match self.segments.get(i) {
+            Some(s) if s.start <= section => s.count,
+            _ => 0,
+        }
+    }
+
+    // Elves whose every section is also assigned to another elf. Each elf is checked on its own,
+    // so two elves with the same assignment are both redundant.
+    pub fn redundant(&self) -> Vec<Elf> {
+        // Number of segments with a single elf before each segment
+        let mut single = Vec::with_capacity(self.segments.len() + 1);
+        single.push(0);
+        for s in &self.segments {
+            single.push(single[single.len() - 1] + (s.count == 1) as usize);
+        }
+
+        self.elves
+            .iter()
+            .filter(|elf| {
+                let first = self.segments.partition_point(|s| s.end < elf.start);
+                let last = self.segments.partition_point(|s| s.end < elf.end);
+                single[last + 1] == single[first]
+            })
+            .copied()
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_coverage() {
+        let data = [
+            "2-4,6-8", "2-3,4-5", "5-7,7-9", "2-8,3-7", "6-6,4-6", "2-6,4-8",
+        ]
+        .map(String::from)
+        .to_vec();
+        let coverage = Coverage::from_lines(&data).unwrap();
+        assert_eq!(coverage.covered(), 8);
+        assert_eq!(coverage.gaps(), []);
+        assert_eq!(coverage.max_elves(), 8);
+        assert_eq!(coverage.count_at(6), 8);
+        assert_eq!(coverage.count_at(9), 1);
+        assert_eq!(coverage.count_at(10), 0);
+
+        // Only 7-9 has a section (9) no one else is assigned to
+        let redundant = coverage.redundant();
+        assert_eq!(redundant.len(), 11);
+        assert!(redundant.iter().all(|elf| elf.index != 5));
+    }
+
+    #[test]
+    fn test_gaps_and_duplicates() {
+        let data = ["1-2,5-6", "5-6,9-4294967295"].map(String::from).to_vec();
+        let coverage = Coverage::from_lines(&data).unwrap();
+        assert_eq!(coverage.gaps(), [(3, 4), (7, 8)]);
+        assert_eq!(coverage.covered(), 2 + 2 + (u32::MAX as u64 - 8));
+        assert_eq!(
+            coverage.segments[1],
+            Segment {
+                start: 5,
+                end: 6,
+                count: 2
+            }
+        );
+        let redundant: Vec<usize> = coverage.redundant().iter().map(|e| e.index).collect();
+        assert_eq!(redundant, [1, 2]);
+    }
+}
@@ -0,0 +1,96 @@
+pub mod coverage;
+pub mod render;
+
+use crate::{explain, Error, Result};
+
+fn parse_range(range: &str) -> Option<(u32, u32)> {
+    let (start, end) = range.split_once('-')?;
+    let (start, end) = (start.parse::<u32>().ok()?, end.parse::<u32>().ok()?);
+    (start <= end).then_some((start, end))
+}
+
+fn parse_pair(index: usize, line: &str) -> Result<((u32, u32), (u32, u32))> {
+    // Line should have format: a-b,c-d with a <= b and c <= d
+    line.split_once(',')
+        .and_then(|(r1, r2)| Some((parse_range(r1)?, parse_range(r2)?)))
+        .ok_or_else(|| Error::invalid_line(index, "expected `<start>-<end>,<start>-<end>`"))
+}
+
+// Describes how the two ranges of a pair relate, only used to explain answers
+fn relation(r1: (u32, u32), r2: (u32, u32)) -> &'static str {
+    if r1 == r2 {
+        "equal"
+    } else if r1.0 <= r2.0 && r1.1 >= r2.1 {
+        "first_contains_second"
+    } else if r2.0 <= r1.0 && r2.1 >= r1.1 {
+        "second_contains_first"
+    } else if r1.1 >= r2.0 && r2.1 >= r1.0 {
+        "overlap"
+    } else {
+        "disjoint"
+    }
+}
+
+pub fn part1(lines: Vec<String>) -> Result<u32> {
+    let mut score = 0;
+    for (i, line) in lines.iter().enumerate() {
+        let ((r1_start, r1_end), (r2_start, r2_end)) = parse_pair(i, line)?;
+        explain!(
+            "pair",
+            line = i + 1,
+            relation = relation((r1_start, r1_end), (r2_start, r2_end))
+        );
+
+        // Check if r1 contains r2 or inverse
+        if (r1_start <= r2_start && r1_end >= r2_end) || (r2_start <= r1_start && r2_end >= r1_end)
+        {
+            score += 1;
+        }
+    }
+    Ok(score)
+}
+
+pub fn part2(lines: Vec<String>) -> Result<u32> {
+    let mut score = 0;
+    for (i, line) in lines.iter().enumerate() {
+        let ((r1_start, r1_end), (r2_start, r2_end)) = parse_pair(i, line)?;
+        explain!(
+            "pair",
+            line = i + 1,
+            relation = relation((r1_start, r1_end), (r2_start, r2_end))
+        );
+
+        // Check for overlap
+        if (r1_end >= r2_start && r1_start <= r2_start)
+            || (r2_end >= r1_start && r2_start <= r1_start)
+        {
+            score += 1;
+        }
+    }
+    Ok(score)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        let data = [
+            "2-4,6-8", "2-3,4-5", "5-7,7-9", "2-8,3-7", "6-6,4-6", "2-6,4-8",
+        ]
+        .map(String::from)
+        .to_vec();
+        assert_eq!(part1(data), Ok(2));
+    }
+
+    #[test]
+    fn test_part2() {
+        let data = [
+            "2-4,6-8", "2-3,4-5", "5-7,7-9", "2-8,3-7", "6-6,4-6", "2-6,4-8",
+        ]
+        .map(String::from)
+        .to_vec();
+        assert_eq!(part2(data), Ok(4));
+    }
+}
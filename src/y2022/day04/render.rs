@@ -0,0 +1,223 @@
+// Draws pairs of assignments like the puzzle description does:
+//
+// .234.....  2-4
+// .....678.  6-8
+//
+// Every column is a section, drawn with the last digit of its number. When sections go past 9, an
+// axis with the section numbers is drawn above the pairs, and when they don't fit in the width,
+// every column stands for several sections and assigned ones are drawn with `#`.
+
+use super::parse_pair;
+use crate::{Error, Result};
+
+const OVERLAP_COLOR: &str = "\x1b[1;33m";
+const RESET_COLOR: &str = "\x1b[0m";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RenderOptions {
+    // Highlight the sections assigned to both elves of a pair with ANSI colours
+    pub color: bool,
+    // Lines to draw, starting at 1 and inclusive, all of them if None
+    pub lines: Option<(usize, usize)>,
+    // Maximum number of columns of the drawing, not counting the ranges written after it
+    pub width: usize,
+}
+
+impl Default for RenderOptions {
+    fn default() -> Self {
+        RenderOptions {
+            color: false,
+            lines: None,
+            width: 80,
+        }
+    }
+}
+
+// Sections drawn by each column
+struct Axis {
+    first: u64,
+    last: u64,
+    scale: u64,
+}
+
+impl Axis {
+    fn columns(&self) -> u64 {
+        (self.last - self.first) / self.scale + 1
+    }
+
+    // First and last sections of a column
+    fn bucket(&self, column: u64) -> (u64, u64) {
+        let start = self.first + column * self.scale;
+        (start, (start + self.scale - 1).min(self.last))
+    }
+}
+
+fn intersects(range: (u64, u64), bucket: (u64, u64)) -> bool {
+    range.0 <= bucket.1 && bucket.0 <= range.1
+}
+
+fn draw_axis(axis: &Axis) -> String {
+    // Labels are the first section of their column, with enough room between them
+    let label_width = axis.last.to_string().len() as u64;
+    let spacing = (label_width + 2).max(10);
+    let mut labels = String::new();
+    let mut ticks = String::new();
+    for column in (0..axis.columns()).step_by(spacing as usize) {
+        let label = axis.bucket(column).0.to_string();
+        labels.push_str(&format!("{:<width$}", label, width = spacing as usize));
+        ticks.push_str(&format!("{:<width$}", "|", width = spacing as usize));
+    }
+    format!("{}\n{}\n", labels.trim_end(), ticks.trim_end())
+}
+
+fn draw_range(axis: &Axis, range: (u64, u64), other: (u64, u64), color: bool) -> String {
+    let overlap = (range.0.max(other.0), range.1.min(other.1));
+    let mut row = String::new();
+    for column in 0..axis.columns() {
+        let bucket = axis.bucket(column);
+        if !intersects(range, bucket) {
+            row.push('.');
+            continue;
+        }
+        let c = if axis.scale == 1 {
+            char::from_digit((bucket.0 % 10) as u32, 10).expect("Single digit")
+        } else {
+            '#'
+        };
+        if color && overlap.0 <= overlap.1 && intersects(overlap, bucket) {
+            row.push_str(OVERLAP_COLOR);
+            row.push(c);
+            row.push_str(RESET_COLOR);
+        } else {
+            row.push(c);
+        }
+    }
+    format!("{}  {}-{}\n", row, range.0, range.1)
+}
+
+pub fn render(lines: &[String], options: &RenderOptions) -> Result<String> {
+    if lines.is_empty() {
+        return Err(Error::EmptyInput);
+    }
+    let (first, last) = options.lines.unwrap_or((1, lines.len()));
+    if first == 0 || first > last || last > lines.len() {
+        return Err(Error::NoSolution(format!(
+            "lines {}-{} are not in 1-{}",
+            first,
+            last,
+            lines.len()
+        )));
+    }
+
+    let mut pairs = Vec::with_capacity(last - first + 1);
+    for (i, line) in lines.iter().enumerate().take(last).skip(first - 1) {
+        let (r1, r2) = parse_pair(i, line)?;
+        pairs.push(((r1.0 as u64, r1.1 as u64), (r2.0 as u64, r2.1 as u64)));
+    }
+
+    // Start at section 1 like the puzzle does, or at 0 when it is assigned, unless that doesn't fit
+    // in the width. Only the drawn pairs are taken into account, so the axis may differ between two
+    // ranges of lines.
+    let width = options.width.max(1) as u64;
+    let end = pairs
+        .iter()
+        .map(|(r1, r2)| r1.1.max(r2.1))
+        .max()
+        .unwrap_or(1);
+    let smallest = pairs
+        .iter()
+        .map(|(r1, r2)| r1.0.min(r2.0))
+        .min()
+        .unwrap_or(1);
+    let start = if end - smallest.min(1) < width {
+        smallest.min(1)
+    } else {
+        smallest
+    };
+    let axis = Axis {
+        first: start,
+        last: end,
+        scale: (end - start) / width + 1,
+    };
+
+    let mut drawing = String::new();
+    if axis.scale > 1 || axis.last > 9 {
+        drawing.push_str(&draw_axis(&axis));
+    }
+    for (i, &(r1, r2)) in pairs.iter().enumerate() {
+        if i > 0 {
+            drawing.push('\n');
+        }
+        drawing.push_str(&draw_range(&axis, r1, r2, options.color));
+        drawing.push_str(&draw_range(&axis, r2, r1, options.color));
+    }
+    Ok(drawing)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_example() {
+        let data = [
+            "2-4,6-8", "2-3,4-5", "5-7,7-9", "2-8,3-7", "6-6,4-6", "2-6,4-8",
+        ]
+        .map(String::from)
+        .to_vec();
+        assert!(render(&data, &RenderOptions::default())
+            .unwrap()
+            .starts_with(".234.....  2-4\n.....678.  6-8\n\n.23......  2-3\n...45....  4-5\n"));
+
+        let options = RenderOptions {
+            color: true,
+            lines: Some((3, 3)),
+            ..Default::default()
+        };
+        assert_eq!(
+            render(&data, &options).unwrap(),
+            "....56\x1b[1;33m7\x1b[0m..  5-7\n......\x1b[1;33m7\x1b[0m89  7-9\n"
+        );
+        assert!(render(
+            &data,
+            &RenderOptions {
+                lines: Some((6, 7)),
+                ..Default::default()
+            }
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_render_wide_sections() {
+        let data = [String::from("12-15,14-20")];
+        assert_eq!(
+            render(&data, &RenderOptions::default()).unwrap(),
+            "1         11\n|         |\n...........2345.....  12-15\n.............4567890  14-20\n"
+        );
+
+        let data = [String::from("1-1000,500-600")];
+        let drawing = render(&data, &RenderOptions::default()).unwrap();
+        let rows: Vec<&str> = drawing.lines().collect();
+        assert_eq!(
+            rows[0],
+            "1         131       261       391       521       651       781       911"
+        );
+        assert_eq!(rows[2], format!("{}  1-1000", "#".repeat(77)));
+        assert_eq!(rows[3].matches('#').count(), 9);
+    }
+
+    #[test]
+    fn test_render_section_zero() {
+        let data = [String::from("0-0,0-0")];
+        assert_eq!(
+            render(&data, &RenderOptions::default()).unwrap(),
+            "0  0-0\n0  0-0\n"
+        );
+        let data = [String::from("0-3,2-5")];
+        assert_eq!(
+            render(&data, &RenderOptions::default()).unwrap(),
+            "0123..  0-3\n..2345  2-5\n"
+        );
+    }
+}
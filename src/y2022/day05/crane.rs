@@ -0,0 +1,141 @@
+// Crane models, they only differ in how the crates of a single procedure are moved.
+
+use super::Crate;
+
+pub trait Crane {
+    fn name(&self) -> &str;
+
+    // Moves the top `quantity` crates of `origin` on top of `destination`. The caller checks
+    // that `origin` holds at least `quantity` crates.
+    fn lift(&self, origin: &mut Vec<Crate>, destination: &mut Vec<Crate>, quantity: usize);
+
+    // Reverts a `lift` of `quantity` crates from `origin` to `destination`
+    fn unlift(&self, origin: &mut Vec<Crate>, destination: &mut Vec<Crate>, quantity: usize);
+}
+
+impl<C: Crane + ?Sized> Crane for Box<C> {
+    fn name(&self) -> &str {
+        (**self).name()
+    }
+
+    fn lift(&self, origin: &mut Vec<Crate>, destination: &mut Vec<Crate>, quantity: usize) {
+        (**self).lift(origin, destination, quantity)
+    }
+
+    fn unlift(&self, origin: &mut Vec<Crate>, destination: &mut Vec<Crate>, quantity: usize) {
+        (**self).unlift(origin, destination, quantity)
+    }
+}
+
+// Moves one crate at a time, so the moved crates end up in reverse order
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CrateMover9000;
+
+impl Crane for CrateMover9000 {
+    fn name(&self) -> &str {
+        "CrateMover 9000"
+    }
+
+    fn lift(&self, origin: &mut Vec<Crate>, destination: &mut Vec<Crate>, quantity: usize) {
+        let lifted = origin.split_off(origin.len() - quantity);
+        destination.extend(lifted.into_iter().rev());
+    }
+
+    fn unlift(&self, origin: &mut Vec<Crate>, destination: &mut Vec<Crate>, quantity: usize) {
+        // Reversing twice gives the original order back
+        self.lift(destination, origin, quantity)
+    }
+}
+
+// Moves all the crates at once, keeping their order
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CrateMover9001;
+
+impl Crane for CrateMover9001 {
+    fn name(&self) -> &str {
+        "CrateMover 9001"
+    }
+
+    fn lift(&self, origin: &mut Vec<Crate>, destination: &mut Vec<Crate>, quantity: usize) {
+        destination.extend(origin.split_off(origin.len() - quantity));
+    }
+
+    fn unlift(&self, origin: &mut Vec<Crate>, destination: &mut Vec<Crate>, quantity: usize) {
+        self.lift(destination, origin, quantity)
+    }
+}
+
+// Moves up to `capacity` crates per lift, keeping the order of the crates of each lift. A
+// capacity of 1 is a CrateMover 9000, and a capacity at least as high as every stack is a
+// CrateMover 9001.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CappedCrane {
+    capacity: usize,
+    name: String,
+}
+
+impl CappedCrane {
+    pub fn new(capacity: usize) -> Self {
+        CappedCrane {
+            capacity: capacity.max(1),
+            name: format!("crane lifting {} crates", capacity.max(1)),
+        }
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+}
+
+impl Crane for CappedCrane {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn lift(&self, origin: &mut Vec<Crate>, destination: &mut Vec<Crate>, quantity: usize) {
+        // The first lift takes the top crates, which end up at the bottom of the destination
+        let mut lifted = origin.split_off(origin.len() - quantity);
+        destination.reserve(quantity);
+        for chunk in lifted.rchunks_mut(self.capacity) {
+            destination.extend(chunk.iter_mut().map(std::mem::take));
+        }
+    }
+
+    fn unlift(&self, origin: &mut Vec<Crate>, destination: &mut Vec<Crate>, quantity: usize) {
+        // The destination holds the lifts in order, the first one (a full lift) at the bottom
+        let mut lifted = destination.split_off(destination.len() - quantity);
+        origin.reserve(quantity);
+        for chunk in lifted.chunks_mut(self.capacity).rev() {
+            origin.extend(chunk.iter_mut().map(std::mem::take));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lift(crane: &dyn Crane, quantity: usize) -> String {
+        let mut origin: Vec<Crate> = "ABCDE".chars().map(String::from).collect();
+        let mut destination = vec![String::from("Z")];
+        crane.lift(&mut origin, &mut destination, quantity);
+        assert_eq!(origin.len(), 5 - quantity);
+        let lifted = destination.concat();
+
+        crane.unlift(&mut origin, &mut destination, quantity);
+        assert_eq!(
+            (origin.concat(), destination.concat()),
+            ("ABCDE".into(), "Z".into())
+        );
+        lifted
+    }
+
+    #[test]
+    fn test_cranes() {
+        assert_eq!(lift(&CrateMover9000, 3), "ZEDC");
+        assert_eq!(lift(&CrateMover9001, 3), "ZCDE");
+        assert_eq!(lift(&CappedCrane::new(2), 5), "ZDEBCA");
+        assert_eq!(lift(&CappedCrane::new(1), 4), lift(&CrateMover9000, 4));
+        assert_eq!(lift(&CappedCrane::new(9), 4), lift(&CrateMover9001, 4));
+    }
+}
@@ -0,0 +1,221 @@
+// Stacks drawn like the puzzle input, top crates first and stack numbers last:
+//
+//     [D]
+// [N] [C]
+// [Z] [M] [P]
+//  1   2   3
+//
+// Columns are found from the stack numbers: a crate belongs to the stack whose number is
+// under it. Labels can be longer than one character and lines can be trimmed.
+
+use super::Crate;
+use crate::{Error, Result};
+
+const HIGHLIGHT_COLOR: &str = "\x1b[1;33m";
+const RESET_COLOR: &str = "\x1b[0m";
+
+// Top crates of a stack to draw with ANSI colours
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Highlight {
+    pub stack: usize, // Starting at 1
+    pub count: usize,
+}
+
+// Words of a line with their first and last columns (in chars, inclusive)
+fn words(line: &str) -> Vec<(usize, usize, &str)> {
+    let mut words = Vec::new();
+    let mut start = None;
+    for (column, (i, c)) in line.char_indices().chain([(line.len(), ' ')]).enumerate() {
+        match (start, c == ' ') {
+            (None, false) => start = Some((column, i)),
+            (Some((first, byte)), true) => {
+                words.push((first, column - 1, &line[byte..i]));
+                start = None;
+            }
+            _ => (),
+        }
+    }
+    words
+}
+
+// Errors point at a column of a line, both starting at 1 in the message
+fn invalid(index: usize, column: usize, reason: &str) -> Error {
+    Error::invalid_line(index, format!("column {}: {}", column + 1, reason))
+}
+
+// Reads the drawing at the start of `lines`, up to the first empty line
+pub fn parse_stacks(lines: &[String]) -> Result<Vec<Vec<Crate>>> {
+    if lines.is_empty() {
+        return Err(Error::EmptyInput);
+    }
+    let height = lines
+        .iter()
+        .position(String::is_empty)
+        .unwrap_or(lines.len());
+    let number_row = height
+        .checked_sub(1)
+        .ok_or_else(|| Error::invalid_line(0, "missing stack drawing"))?;
+
+    // Stack numbers go from 1 to n, their centers are kept doubled to stay integers
+    let mut centers = Vec::new();
+    for (first, last, word) in words(&lines[number_row]) {
+        let number = word.parse::<usize>().ok();
+        if number.is_none() && centers.is_empty() {
+            return Err(Error::invalid_line(number_row, "missing stack numbers"));
+        }
+        if number != Some(centers.len() + 1) {
+            return Err(invalid(
+                number_row,
+                first,
+                &format!("expected stack number {}", centers.len() + 1),
+            ));
+        }
+        centers.push(first + last);
+    }
+    if centers.is_empty() {
+        return Err(Error::invalid_line(number_row, "missing stack numbers"));
+    }
+
+    let mut stacks: Vec<Vec<Crate>> = vec![Vec::new(); centers.len()];
+    for index in (0..number_row).rev() {
+        for (first, last, word) in words(&lines[index]) {
+            let label = word
+                .strip_prefix('[')
+                .and_then(|w| w.strip_suffix(']'))
+                .filter(|label| !label.is_empty() && !label.contains(['[', ']']))
+                .ok_or_else(|| invalid(index, first, "expected a crate like `[A]`"))?;
+
+            // Centers are sorted, so only the first one after the start can be under the crate
+            let stack = centers.partition_point(|&center| center < 2 * first);
+            let is_under = |s: usize| centers.get(s).is_some_and(|&center| center <= 2 * last);
+            if !is_under(stack) {
+                return Err(invalid(index, first, "crate is not above a stack"));
+            }
+            if is_under(stack + 1) {
+                return Err(invalid(index, first, "crate is above several stacks"));
+            }
+            // Rows are read bottom up, so every crate must sit on the previous row
+            if stacks[stack].len() != number_row - 1 - index {
+                return Err(invalid(index, first, "crate is floating above the stack"));
+            }
+            stacks[stack].push(String::from(label));
+        }
+    }
+    Ok(stacks)
+}
+
+// Lines of the drawing. Every column is as wide as the longest label, crates and numbers are
+// centered, and crate rows are padded with spaces to the full width.
+pub fn render_stacks(stacks: &[Vec<Crate>], highlight: Option<Highlight>) -> Vec<String> {
+    let label_width = stacks.iter().flatten().map(|c| c.chars().count());
+    let number_width = stacks.len().to_string().len();
+    let width = label_width
+        .map(|w| w + 2)
+        .max()
+        .unwrap_or(3)
+        .max(number_width);
+    let center = |s: &str, len: usize| {
+        let left = (width - len) / 2;
+        format!(
+            "{}{}{}",
+            " ".repeat(left),
+            s,
+            " ".repeat(width - len - left)
+        )
+    };
+
+    let height = stacks.iter().map(Vec::len).max().unwrap_or(0);
+    let mut lines = Vec::with_capacity(height + 1);
+    for level in (0..height).rev() {
+        let mut line = String::new();
+        for (i, stack) in stacks.iter().enumerate() {
+            if i > 0 {
+                line.push(' ');
+            }
+            let Some(label) = stack.get(level) else {
+                line.push_str(&" ".repeat(width));
+                continue;
+            };
+            let crate_ = center(&format!("[{}]", label), label.chars().count() + 2);
+            let highlighted =
+                highlight.is_some_and(|h| h.stack == i + 1 && level + h.count >= stack.len());
+            if highlighted {
+                let trimmed = crate_.trim();
+                let (left, right) = crate_.split_once(trimmed).expect("Contains itself");
+                line.push_str(&format!(
+                    "{}{}{}{}{}",
+                    left, HIGHLIGHT_COLOR, trimmed, RESET_COLOR, right
+                ));
+            } else {
+                line.push_str(&crate_);
+            }
+        }
+        lines.push(line);
+    }
+
+    let numbers = (1..=stacks.len())
+        .map(|n| center(&n.to_string(), n.to_string().len()))
+        .collect::<Vec<_>>()
+        .join(" ");
+    lines.push(String::from(numbers.trim_end()));
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn to_lines(lines: &[&str]) -> Vec<String> {
+        lines.iter().map(|&l| String::from(l)).collect()
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let drawing = to_lines(&["    [D]    ", "[N] [C]    ", "[Z] [M] [P]", " 1   2   3"]);
+        let stacks = parse_stacks(&drawing).unwrap();
+        assert_eq!(render_stacks(&stacks, None), drawing);
+
+        let highlight = Highlight { stack: 2, count: 2 };
+        assert_eq!(
+            render_stacks(&stacks, Some(highlight))[1],
+            "[N] \x1b[1;33m[C]\x1b[0m    "
+        );
+
+        // Trimmed lines, 10+ stacks and long labels
+        let mut stacks = vec![vec![String::from("A")]; 12];
+        stacks[10].push(String::from("LONG"));
+        let drawing = render_stacks(&stacks, None);
+        assert_eq!(drawing[0].trim(), "[LONG]");
+        assert_eq!(
+            parse_stacks(
+                &drawing
+                    .iter()
+                    .map(|l| String::from(l.trim_end()))
+                    .collect::<Vec<_>>()
+            ),
+            Ok(stacks)
+        );
+    }
+
+    #[test]
+    fn test_malformed_drawings() {
+        let error = |lines: &[&str]| parse_stacks(&to_lines(lines)).unwrap_err().to_string();
+        assert_eq!(
+            error(&["[A] [B]", " 1   3"]),
+            "Invalid line 2: column 6: expected stack number 2"
+        );
+        assert_eq!(
+            error(&["[A] B", " 1   2"]),
+            "Invalid line 1: column 5: expected a crate like `[A]`"
+        );
+        assert_eq!(
+            error(&["  [A]", " 1   2"]),
+            "Invalid line 1: column 3: crate is not above a stack"
+        );
+        assert_eq!(
+            error(&["[A]", "    [B]", " 1   2"]),
+            "Invalid line 1: column 1: crate is floating above the stack"
+        );
+        assert_eq!(error(&["[A]", ""]), "Invalid line 1: missing stack numbers");
+    }
+}
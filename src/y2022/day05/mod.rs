@@ -0,0 +1,151 @@
+mod crane;
+mod drawing;
+mod planner;
+mod simulation;
+
+use std::fmt;
+
+pub use crane::{CappedCrane, Crane, CrateMover9000, CrateMover9001};
+pub use drawing::{parse_stacks, render_stacks, Highlight};
+pub use planner::{plan, plan_stacks, Objective, Plan, PlanOptions};
+pub use simulation::{Simulation, DEFAULT_PLACEHOLDER};
+
+use crate::{Error, Result};
+
+// Crate labels are usually a single letter, but any label without spaces or brackets is allowed
+pub type Crate = String;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Procedure {
+    pub quantity: usize,
+    pub origin: usize,
+    pub destination: usize,
+}
+
+impl TryFrom<&String> for Procedure {
+    type Error = &'static str;
+
+    fn try_from(item: &String) -> std::result::Result<Self, Self::Error> {
+        // String should have format: move x from y to z
+        let words: Vec<&str> = item.split(' ').collect();
+        let (quantity, origin, destination) = match words[..] {
+            ["move", quantity, "from", origin, "to", destination] => {
+                (quantity, origin, destination)
+            }
+            _ => return Err("expected `move <quantity> from <origin> to <destination>`"),
+        };
+        let parse = |n: &str| {
+            n.parse::<usize>()
+                .map_err(|_| "invalid number in procedure")
+        };
+
+        Ok(Procedure {
+            quantity: parse(quantity)?,
+            origin: parse(origin)?,
+            destination: parse(destination)?,
+        })
+    }
+}
+
+impl fmt::Display for Procedure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "move {} from {} to {}",
+            self.quantity, self.origin, self.destination
+        )
+    }
+}
+
+// Stacks from bottom to top, e.g. `1:ZN 2:MCD 3:P`, only used to explain answers
+fn describe_stacks(stacks: &[Vec<Crate>]) -> String {
+    stacks
+        .iter()
+        .enumerate()
+        .map(|(i, stack)| format!("{}:{}", i + 1, stack.concat()))
+        .collect::<Vec<String>>()
+        .join(" ")
+}
+
+// Top crate of every stack, with `placeholder` for empty stacks
+fn top_crates(stacks: &[Vec<Crate>], placeholder: char) -> String {
+    let mut answer = String::new();
+    for stack in stacks {
+        match stack.last() {
+            Some(top) => answer.push_str(top),
+            None => answer.push(placeholder),
+        }
+    }
+    answer
+}
+
+pub fn part1(lines: Vec<String>) -> Result<String> {
+    let mut simulation = Simulation::from_lines(CrateMover9000, &lines)?;
+    simulation.run()?;
+    Ok(simulation.top_crates())
+}
+
+pub fn part2(lines: Vec<String>) -> Result<String> {
+    let mut simulation = Simulation::from_lines(CrateMover9001, &lines)?;
+    simulation.run()?;
+    Ok(simulation.top_crates())
+}
+
+fn parse_procedures(lines: &[String]) -> Result<Vec<(usize, Procedure)>> {
+    // Skip lines until we find the empty line (signifies end of stack definition, start of
+    // procedures)
+    let lines_iter = lines
+        .iter()
+        .enumerate()
+        .skip_while(|(_, s)| !s.is_empty())
+        .skip(1);
+    let mut procedures: Vec<(usize, Procedure)> = Vec::new();
+
+    // Convert line to procedure struct, keeping its index for error messages
+    for (i, line) in lines_iter {
+        let procedure = Procedure::try_from(line).map_err(|e| Error::invalid_line(i, e))?;
+        procedures.push((i, procedure));
+    }
+    Ok(procedures)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        let data = [
+            "    [D]    ",
+            "[N] [C]    ",
+            "[Z] [M] [P]",
+            " 1   2   3",
+            "",
+            "move 1 from 2 to 1",
+            "move 3 from 1 to 3",
+            "move 2 from 2 to 1",
+            "move 1 from 1 to 2",
+        ]
+        .map(String::from)
+        .to_vec();
+        assert_eq!(part1(data), Ok(String::from("CMZ")));
+    }
+
+    #[test]
+    fn test_part2() {
+        let data = [
+            "    [D]    ",
+            "[N] [C]    ",
+            "[Z] [M] [P]",
+            " 1   2   3",
+            "",
+            "move 1 from 2 to 1",
+            "move 3 from 1 to 3",
+            "move 2 from 2 to 1",
+            "move 1 from 1 to 2",
+        ]
+        .map(String::from)
+        .to_vec();
+        assert_eq!(part2(data), Ok(String::from("MCD")));
+    }
+}
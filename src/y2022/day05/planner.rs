@@ -0,0 +1,459 @@
+// Plans the procedures that turn one arrangement of the stacks into another. Arrangements are
+// searched cheapest first (A*), which gives the fewest procedures or the fewest moved crates, but
+// their number grows quickly with the number of crates. Past a number of visited arrangements, a
+// greedy plan that builds the target stacks from the bottom up is returned instead.
+
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap};
+
+use super::crane::Crane;
+use super::simulation::stack_pair;
+use super::{parse_stacks, Crate, Procedure};
+use crate::{Error, Result};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Objective {
+    // Fewest procedures, then fewest moved crates
+    Procedures,
+    // Fewest moved crates, then fewest procedures
+    Crates,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PlanOptions {
+    pub objective: Objective,
+    // Arrangements the search may visit before falling back to a greedy plan
+    pub max_states: usize,
+}
+
+impl Default for PlanOptions {
+    fn default() -> Self {
+        PlanOptions {
+            objective: Objective::Procedures,
+            max_states: 100_000,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Plan {
+    pub procedures: Vec<Procedure>,
+    // False for greedy plans, which may not be the cheapest
+    pub optimal: bool,
+}
+
+impl Plan {
+    pub fn crates_moved(&self) -> usize {
+        self.procedures.iter().map(|p| p.quantity).sum()
+    }
+}
+
+// Both arrangements are drawings like the puzzle input, stack numbers included
+pub fn plan<C: Crane>(
+    crane: &C,
+    start: &[String],
+    target: &[String],
+    options: &PlanOptions,
+) -> Result<Plan> {
+    plan_stacks(crane, parse_stacks(start)?, &parse_stacks(target)?, options)
+}
+
+pub fn plan_stacks<C: Crane>(
+    crane: &C,
+    start: Vec<Vec<Crate>>,
+    target: &[Vec<Crate>],
+    options: &PlanOptions,
+) -> Result<Plan> {
+    if start.len() != target.len() {
+        return Err(Error::NoSolution(format!(
+            "start has {} stacks but target has {}",
+            start.len(),
+            target.len()
+        )));
+    }
+    fn labels(stacks: &[Vec<Crate>]) -> Vec<&Crate> {
+        let mut labels: Vec<&Crate> = stacks.iter().flatten().collect();
+        labels.sort_unstable();
+        labels
+    }
+    if labels(&start) != labels(target) {
+        return Err(Error::NoSolution(String::from(
+            "start and target don't have the same crates",
+        )));
+    }
+
+    if let Some(procedures) = search(crane, &start, target, options)? {
+        return Ok(Plan {
+            procedures,
+            optimal: true,
+        });
+    }
+    let procedures = greedy(crane, start, target).ok_or_else(|| {
+        Error::NoSolution(format!(
+            "no plan found within {} arrangements",
+            options.max_states
+        ))
+    })?;
+    Ok(Plan {
+        procedures,
+        optimal: false,
+    })
+}
+
+// Procedures and moved crates, in the order they are minimised
+type Cost = (usize, usize);
+
+fn cost(objective: Objective, procedures: usize, crates: usize) -> Cost {
+    match objective {
+        Objective::Procedures => (procedures, crates),
+        Objective::Crates => (crates, procedures),
+    }
+}
+
+// Number of crates at the bottom of a stack that are already where the target wants them
+fn finished(stack: &[Crate], target: &[Crate]) -> usize {
+    stack.iter().zip(target).take_while(|(a, b)| a == b).count()
+}
+
+// Every crate above the finished part of a stack moves at least once, and every stack with such
+// crates or with missing crates is the origin or the destination of a procedure
+fn lower_bound(stacks: &[Vec<Crate>], target: &[Vec<Crate>]) -> (usize, usize) {
+    let (mut origins, mut destinations, mut lifted, mut placed) = (0, 0, 0, 0);
+    for (stack, target) in stacks.iter().zip(target) {
+        let done = finished(stack, target);
+        origins += (stack.len() > done) as usize;
+        destinations += (target.len() > done) as usize;
+        lifted += stack.len() - done;
+        placed += target.len() - done;
+    }
+    (origins.max(destinations), lifted.max(placed))
+}
+
+struct Node {
+    stacks: Vec<Vec<Crate>>,
+    parent: usize,
+    procedure: Option<Procedure>,
+    procedures: usize,
+    crates: usize,
+}
+
+// None if the search gives up after `max_states` arrangements
+fn search<C: Crane>(
+    crane: &C,
+    start: &[Vec<Crate>],
+    target: &[Vec<Crate>],
+    options: &PlanOptions,
+) -> Result<Option<Vec<Procedure>>> {
+    let objective = options.objective;
+    let mut nodes = vec![Node {
+        stacks: start.to_vec(),
+        parent: 0,
+        procedure: None,
+        procedures: 0,
+        crates: 0,
+    }];
+    // Cheapest node found for every arrangement. Nodes that were beaten stay in the heap and are
+    // skipped when popped.
+    let mut cheapest = HashMap::from([(start.to_vec(), 0)]);
+    let (procedures, crates) = lower_bound(start, target);
+    let mut heap = BinaryHeap::from([Reverse((cost(objective, procedures, crates), 0))]);
+
+    while let Some(Reverse((_, index))) = heap.pop() {
+        let node = &nodes[index];
+        if cheapest[&node.stacks] != index {
+            continue;
+        }
+        if node.stacks == target {
+            let mut procedures = Vec::new();
+            let mut index = index;
+            while let Some(procedure) = nodes[index].procedure {
+                procedures.push(procedure);
+                index = nodes[index].parent;
+            }
+            procedures.reverse();
+            return Ok(Some(procedures));
+        }
+        if nodes.len() > options.max_states {
+            return Ok(None);
+        }
+
+        let mut children = Vec::new();
+        for origin in 0..node.stacks.len() {
+            for destination in (0..node.stacks.len()).filter(|&d| d != origin) {
+                for quantity in 1..=node.stacks[origin].len() {
+                    let mut stacks = node.stacks.clone();
+                    let (o, d) = stack_pair(&mut stacks, origin + 1, destination + 1);
+                    crane.lift(o, d.expect("Different stacks"), quantity);
+                    let procedure = Procedure {
+                        quantity,
+                        origin: origin + 1,
+                        destination: destination + 1,
+                    };
+                    children.push((stacks, procedure));
+                }
+            }
+        }
+        let (procedures, crates) = (node.procedures + 1, node.crates);
+        for (stacks, procedure) in children {
+            let crates = crates + procedure.quantity;
+            if let Some(&other) = cheapest.get(&stacks) {
+                let other: &Node = &nodes[other];
+                if cost(objective, other.procedures, other.crates)
+                    <= cost(objective, procedures, crates)
+                {
+                    continue;
+                }
+            }
+            let bound = lower_bound(&stacks, target);
+            let estimate = cost(objective, procedures + bound.0, crates + bound.1);
+            heap.push(Reverse((estimate, nodes.len())));
+            cheapest.insert(stacks.clone(), nodes.len());
+            nodes.push(Node {
+                stacks,
+                parent: index,
+                procedure: Some(procedure),
+                procedures,
+                crates,
+            });
+        }
+    }
+    Err(Error::NoSolution(format!(
+        "the target can't be reached with the {}",
+        crane.name()
+    )))
+}
+
+// Cranes only rearrange crates, so lifting numbered crates tells in which order a block of
+// `quantity` crates lands: the k-th crate from the bottom comes from `order[k]` in the block
+fn landing_order<C: Crane>(crane: &C, quantity: usize) -> Vec<usize> {
+    let mut origin: Vec<Crate> = (0..quantity).map(|i| i.to_string()).collect();
+    let mut destination = Vec::with_capacity(quantity);
+    crane.lift(&mut origin, &mut destination, quantity);
+    destination
+        .iter()
+        .map(|i| i.parse().expect("Numbered crate"))
+        .collect()
+}
+
+// Stack to put crates that are in the way on, preferably one that has to be cleared anyway
+fn dump_stack(
+    stacks: &[Vec<Crate>],
+    target: &[Vec<Crate>],
+    done: &[usize],
+    excluded: &[usize],
+) -> Option<usize> {
+    (0..stacks.len())
+        .filter(|s| !excluded.contains(s))
+        .min_by_key(|&s| (stacks[s].len() == done[s], target[s].len() - done[s]))
+}
+
+fn apply<C: Crane>(
+    crane: &C,
+    stacks: &mut [Vec<Crate>],
+    procedures: &mut Vec<Procedure>,
+    procedure: Procedure,
+) {
+    let (origin, destination) = stack_pair(stacks, procedure.origin, procedure.destination);
+    crane.lift(
+        origin,
+        destination.expect("Different stacks"),
+        procedure.quantity,
+    );
+    procedures.push(procedure);
+}
+
+// Every step clears an incomplete stack down to its finished part, or puts crates on such a
+// stack in the target order. Crates above the ones to move go on a third stack, so this fails
+// when there are less than three stacks.
+fn greedy<C: Crane>(
+    crane: &C,
+    mut stacks: Vec<Vec<Crate>>,
+    target: &[Vec<Crate>],
+) -> Option<Vec<Procedure>> {
+    let mut procedures = Vec::new();
+    let mut orders: Vec<Vec<usize>> = Vec::new();
+    loop {
+        let done: Vec<usize> = stacks
+            .iter()
+            .zip(target)
+            .map(|(stack, target)| finished(stack, target))
+            .collect();
+        let extra = |s: usize| stacks[s].len() - done[s];
+        // Both arrangements have as many crates, so none are left over once every stack is done
+        let Some(stack) = (0..stacks.len())
+            .filter(|&s| done[s] < target[s].len())
+            .min_by_key(|&s| extra(s))
+        else {
+            return Some(procedures);
+        };
+
+        if extra(stack) > 0 {
+            let dump = dump_stack(&stacks, target, &done, &[stack])?;
+            let procedure = Procedure {
+                quantity: extra(stack),
+                origin: stack + 1,
+                destination: dump + 1,
+            };
+            apply(crane, &mut stacks, &mut procedures, procedure);
+            continue;
+        }
+
+        // Block of crates that lands in the target order, after moving the `above` crates over
+        // it out of the way. The most crates per procedure is best.
+        let wanted = &target[stack][done[stack]..];
+        let steps = |above: usize| 1 + (above > 0) as usize;
+        let mut best: Option<(usize, usize, usize)> = None;
+        for origin in (0..stacks.len()).filter(|&s| s != stack) {
+            for above in 0..extra(origin) {
+                let top = stacks[origin].len() - above;
+                for quantity in 1..=(extra(origin) - above).min(wanted.len()) {
+                    while orders.len() <= quantity {
+                        orders.push(landing_order(crane, orders.len()));
+                    }
+                    let block = &stacks[origin][top - quantity..top];
+                    if !orders[quantity]
+                        .iter()
+                        .zip(wanted)
+                        .all(|(&i, label)| block[i] == *label)
+                    {
+                        continue;
+                    }
+                    let better = best.is_none_or(|(_, a, q)| {
+                        let (gain, other) = (quantity * steps(a), q * steps(above));
+                        gain > other || (gain == other && above < a)
+                    });
+                    if better {
+                        best = Some((origin, above, quantity));
+                    }
+                }
+            }
+        }
+
+        // The next wanted crate is above the finished part of some other stack
+        let (origin, above, quantity) = best.expect("Crates are the same");
+        if above > 0 {
+            let dump = dump_stack(&stacks, target, &done, &[stack, origin])?;
+            let procedure = Procedure {
+                quantity: above,
+                origin: origin + 1,
+                destination: dump + 1,
+            };
+            apply(crane, &mut stacks, &mut procedures, procedure);
+        }
+        let procedure = Procedure {
+            quantity,
+            origin: origin + 1,
+            destination: stack + 1,
+        };
+        apply(crane, &mut stacks, &mut procedures, procedure);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::crane::{CrateMover9000, CrateMover9001};
+    use super::super::Simulation;
+    use super::*;
+
+    fn start() -> Vec<String> {
+        ["    [D]    ", "[N] [C]    ", "[Z] [M] [P]", " 1   2   3"]
+            .map(String::from)
+            .to_vec()
+    }
+
+    // Runs the plan written out as procedure lines after the start drawing
+    fn check<C: Crane>(crane: C, plan: &Plan, target: &[String]) {
+        let mut lines = start();
+        lines.push(String::new());
+        lines.extend(plan.procedures.iter().map(|p| p.to_string()));
+        let mut simulation = Simulation::from_lines(crane, &lines).unwrap();
+        simulation.run().unwrap();
+        assert_eq!(simulation.stacks(), parse_stacks(target).unwrap());
+    }
+
+    #[test]
+    fn test_plan_example() {
+        // Where part 2 leaves the crates
+        let target = [
+            "        [D]",
+            "        [N]",
+            "        [Z]",
+            "[M] [C] [P]",
+            " 1   2   3",
+        ]
+        .map(String::from);
+        let options = PlanOptions::default();
+
+        let plan_9001 = plan(&CrateMover9001, &start(), &target, &options).unwrap();
+        assert!(plan_9001.optimal);
+        assert_eq!(plan_9001.procedures.len(), 4);
+        check(CrateMover9001, &plan_9001, &target);
+
+        // The CrateMover 9000 can save a crate with one more procedure
+        let plan_9000 = plan(&CrateMover9000, &start(), &target, &options).unwrap();
+        assert_eq!(
+            (plan_9000.procedures.len(), plan_9000.crates_moved()),
+            (4, 8)
+        );
+        check(CrateMover9000, &plan_9000, &target);
+        let crates = PlanOptions {
+            objective: Objective::Crates,
+            ..Default::default()
+        };
+        let fewest_crates = plan(&CrateMover9000, &start(), &target, &crates).unwrap();
+        assert_eq!(
+            (fewest_crates.procedures.len(), fewest_crates.crates_moved()),
+            (5, 7)
+        );
+        check(CrateMover9000, &fewest_crates, &target);
+
+        // Without searching, the greedy plan still reaches the target
+        let greedy = PlanOptions {
+            max_states: 0,
+            ..Default::default()
+        };
+        for options in [
+            greedy.clone(),
+            PlanOptions {
+                objective: Objective::Crates,
+                ..greedy
+            },
+        ] {
+            let plan = plan(&CrateMover9000, &start(), &target, &options).unwrap();
+            assert!(!plan.optimal);
+            check(CrateMover9000, &plan, &target);
+        }
+    }
+
+    #[test]
+    fn test_impossible_plans() {
+        let options = PlanOptions::default();
+        let lines = |lines: &[&str]| lines.iter().map(|&l| String::from(l)).collect::<Vec<_>>();
+        let error = |crane: Box<dyn Crane>, start: &[&str], target: &[&str]| {
+            plan(&crane, &lines(start), &lines(target), &options)
+                .unwrap_err()
+                .to_string()
+        };
+        assert_eq!(
+            error(Box::new(CrateMover9001), &["[A]", " 1"], &["[B]", " 1"]),
+            "No solution: start and target don't have the same crates"
+        );
+        assert_eq!(
+            error(
+                Box::new(CrateMover9001),
+                &["[A]", " 1"],
+                &["    [A]", " 1   2"]
+            ),
+            "No solution: start has 1 stacks but target has 2"
+        );
+
+        // With two stacks, the CrateMover 9000 can't change the order of the crates
+        let (start, target) = (["[B]", "[A]", " 1   2"], ["[A]", "[B]", " 1   2"]);
+        assert_eq!(
+            error(Box::new(CrateMover9000), &start, &target),
+            "No solution: the target can't be reached with the CrateMover 9000"
+        );
+        let plan = plan(&CrateMover9001, &lines(&start), &lines(&target), &options).unwrap();
+        assert_eq!(plan.procedures.len(), 3);
+    }
+}
@@ -0,0 +1,320 @@
+// Applies procedures one at a time. Cranes know how to revert their own moves, so any step can be
+// undone and redone without replaying the whole procedure list or keeping copies of the stacks.
+
+use super::crane::Crane;
+use super::Crate;
+use super::{describe_stacks, parse_procedures, parse_stacks, top_crates, Procedure};
+use crate::{explain, Error, Result};
+
+pub struct Simulation<C: Crane> {
+    crane: C,
+    stacks: Vec<Vec<Crate>>,
+    // Applied procedures first, then the ones that can be redone. Lines start at 0.
+    procedures: Vec<(usize, Procedure)>,
+    // Number of applied procedures
+    step: usize,
+    // Stands for empty stacks in the top crates
+    placeholder: char,
+}
+
+// Used for empty stacks unless another placeholder is set. Labels can't contain spaces, so it
+// can't be mistaken for a crate.
+pub const DEFAULT_PLACEHOLDER: char = ' ';
+
+impl<C: Crane> Simulation<C> {
+    pub fn new(crane: C, stacks: Vec<Vec<Crate>>) -> Self {
+        Simulation {
+            crane,
+            stacks,
+            procedures: Vec::new(),
+            step: 0,
+            placeholder: DEFAULT_PLACEHOLDER,
+        }
+    }
+
+    pub fn with_placeholder(mut self, placeholder: char) -> Self {
+        self.placeholder = placeholder;
+        self
+    }
+
+    // Stacks and procedures of a puzzle input, none of the procedures are applied yet
+    pub fn from_lines(crane: C, lines: &[String]) -> Result<Self> {
+        let mut simulation = Simulation::new(crane, parse_stacks(lines)?);
+        simulation.procedures = parse_procedures(lines)?;
+        Ok(simulation)
+    }
+
+    pub fn crane(&self) -> &C {
+        &self.crane
+    }
+
+    pub fn stacks(&self) -> &[Vec<Crate>] {
+        &self.stacks
+    }
+
+    // Number of applied procedures
+    pub fn step(&self) -> usize {
+        self.step
+    }
+
+    // Number of procedures, applied or not
+    pub fn len(&self) -> usize {
+        self.procedures.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.procedures.is_empty()
+    }
+
+    pub fn procedures(&self) -> &[(usize, Procedure)] {
+        &self.procedures
+    }
+
+    // Applies a new procedure, forgetting the procedures that could be redone. A rejected
+    // procedure leaves the history as it was.
+    pub fn apply(&mut self, line: usize, procedure: Procedure) -> Result<()> {
+        let stacks = &self.stacks;
+        check_procedure(stacks.len(), |i| stacks[i].len(), line, procedure)?;
+        self.procedures.truncate(self.step());
+        self.procedures.push((line, procedure));
+        self.redo()?;
+        Ok(())
+    }
+
+    // Applies the next procedure, returns false if they all are
+    pub fn redo(&mut self) -> Result<bool> {
+        let Some(&(line, procedure)) = self.procedures.get(self.step()) else {
+            return Ok(false);
+        };
+        let stacks = &self.stacks;
+        check_procedure(stacks.len(), |i| stacks[i].len(), line, procedure)?;
+
+        let (origin, destination) =
+            stack_pair(&mut self.stacks, procedure.origin, procedure.destination);
+        if let Some(destination) = destination {
+            self.crane.lift(origin, destination, procedure.quantity);
+        }
+        self.step += 1;
+        explain!(
+            "procedure",
+            line = line + 1,
+            stacks = describe_stacks(&self.stacks)
+        );
+        Ok(true)
+    }
+
+    // Reverts the last applied procedure, returns false if none are
+    pub fn undo(&mut self) -> bool {
+        if self.step == 0 {
+            return false;
+        }
+        self.step -= 1;
+        let (_, procedure) = self.procedures[self.step];
+        let (origin, destination) =
+            stack_pair(&mut self.stacks, procedure.origin, procedure.destination);
+        if let Some(destination) = destination {
+            self.crane.unlift(origin, destination, procedure.quantity);
+        }
+        true
+    }
+
+    // Checks that every remaining procedure can be applied, without moving any crate
+    pub fn validate(&self) -> Result<()> {
+        let mut heights: Vec<usize> = self.stacks.iter().map(Vec::len).collect();
+        for &(line, procedure) in &self.procedures[self.step..] {
+            check_procedure(heights.len(), |i| heights[i], line, procedure)?;
+            heights[procedure.origin - 1] -= procedure.quantity;
+            heights[procedure.destination - 1] += procedure.quantity;
+        }
+        Ok(())
+    }
+
+    // Applies every remaining procedure
+    pub fn run(&mut self) -> Result<()> {
+        while self.redo()? {}
+        Ok(())
+    }
+
+    // Undoes or redoes procedures until `step` of them are applied
+    pub fn seek(&mut self, step: usize) -> Result<()> {
+        if step > self.len() {
+            return Err(Error::NoSolution(format!(
+                "step {} is after the last procedure ({})",
+                step,
+                self.len()
+            )));
+        }
+        while self.step() > step {
+            self.undo();
+        }
+        while self.step() < step {
+            self.redo()?;
+        }
+        Ok(())
+    }
+
+    pub fn top_crates(&self) -> String {
+        top_crates(&self.stacks, self.placeholder)
+    }
+
+    // Top crates once `step` procedures are applied, the simulation is left at that step
+    pub fn top_crates_at(&mut self, step: usize) -> Result<String> {
+        self.seek(step)?;
+        Ok(self.top_crates())
+    }
+}
+
+// Checks a procedure against `count` stacks, `height(i)` crates high. Sizes are only gathered
+// for the error message, so that a valid step doesn't depend on the number of stacks.
+fn check_procedure(
+    count: usize,
+    height: impl Fn(usize) -> usize,
+    line: usize,
+    procedure: Procedure,
+) -> Result<()> {
+    let sizes = || {
+        (0..count)
+            .map(|i| format!("{}:{}", i + 1, height(i)))
+            .collect::<Vec<_>>()
+            .join(" ")
+    };
+    for n in [procedure.origin, procedure.destination] {
+        if n == 0 || n > count {
+            return Err(Error::invalid_line(
+                line,
+                format!("stack {} does not exist (stack sizes {})", n, sizes()),
+            ));
+        }
+    }
+    let origin_height = height(procedure.origin - 1);
+    if origin_height < procedure.quantity {
+        return Err(Error::invalid_line(
+            line,
+            format!(
+                "cannot move {} crates from stack {} holding {} (stack sizes {})",
+                procedure.quantity,
+                procedure.origin,
+                origin_height,
+                sizes()
+            ),
+        ));
+    }
+    Ok(())
+}
+
+// Origin and destination stacks (None if it's the origin), both must exist
+pub(super) fn stack_pair(
+    stacks: &mut [Vec<Crate>],
+    origin: usize,
+    destination: usize,
+) -> (&mut Vec<Crate>, Option<&mut Vec<Crate>>) {
+    let (o, d) = (origin - 1, destination - 1);
+    if o == d {
+        return (&mut stacks[o], None);
+    }
+    // Split so that both stacks can be borrowed at once
+    let (low, high) = stacks.split_at_mut(o.max(d));
+    let (first, second) = (&mut low[o.min(d)], &mut high[0]);
+    if o < d {
+        (first, Some(second))
+    } else {
+        (second, Some(first))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::crane::{CrateMover9000, CrateMover9001};
+    use super::*;
+
+    fn example() -> Vec<String> {
+        [
+            "    [D]    ",
+            "[N] [C]    ",
+            "[Z] [M] [P]",
+            " 1   2   3",
+            "",
+            "move 1 from 2 to 1",
+            "move 3 from 1 to 3",
+            "move 2 from 2 to 1",
+            "move 1 from 1 to 2",
+        ]
+        .map(String::from)
+        .to_vec()
+    }
+
+    #[test]
+    fn test_undo_redo() {
+        let mut simulation = Simulation::from_lines(CrateMover9001, &example()).unwrap();
+        let initial = simulation.stacks().to_vec();
+        simulation.run().unwrap();
+        assert_eq!(simulation.step(), 4);
+        assert_eq!(simulation.top_crates(), "MCD");
+
+        assert_eq!(simulation.top_crates_at(1), Ok(String::from("DCP")));
+        // Every crate of the first stack was moved
+        assert_eq!(simulation.top_crates_at(2), Ok(String::from(" CD")));
+        assert!(simulation.undo() && simulation.undo());
+        assert_eq!(simulation.stacks(), initial);
+        assert!(!simulation.undo());
+        assert!(simulation.seek(5).is_err());
+
+        // Applying a new procedure forgets the ones that could be redone
+        simulation.redo().unwrap();
+        simulation
+            .apply(
+                9,
+                Procedure {
+                    quantity: 2,
+                    origin: 1,
+                    destination: 1,
+                },
+            )
+            .unwrap();
+        assert_eq!(simulation.len(), 2);
+        assert!(!simulation.redo().unwrap());
+        assert_eq!(simulation.top_crates(), "DCP");
+    }
+
+    #[test]
+    fn test_undo_one_by_one() {
+        let mut simulation = Simulation::from_lines(CrateMover9000, &example()).unwrap();
+        simulation.run().unwrap();
+        assert_eq!(simulation.top_crates(), "CMZ");
+        let end = simulation.stacks().to_vec();
+        simulation.seek(0).unwrap();
+        simulation.seek(4).unwrap();
+        assert_eq!(simulation.stacks(), end);
+    }
+
+    #[test]
+    fn test_validation() {
+        let mut lines = example();
+        lines[7] = String::from("move 4 from 2 to 1");
+        let simulation = Simulation::from_lines(CrateMover9001, &lines).unwrap();
+        let error = String::from(
+            "Invalid line 8: cannot move 4 crates from stack 2 holding 2 (stack sizes 1:0 2:2 3:4)",
+        );
+        assert_eq!(
+            simulation.validate().map_err(|e| e.to_string()),
+            Err(error.clone())
+        );
+
+        let mut simulation = simulation.with_placeholder('_');
+        assert_eq!(simulation.run().map_err(|e| e.to_string()), Err(error));
+        assert_eq!(simulation.top_crates(), "_CD");
+
+        let procedure = Procedure {
+            quantity: 1,
+            origin: 3,
+            destination: 4,
+        };
+        assert_eq!(
+            simulation.apply(9, procedure).map_err(|e| e.to_string()),
+            Err(String::from(
+                "Invalid line 10: stack 4 does not exist (stack sizes 1:0 2:2 3:4)"
+            ))
+        );
+        assert_eq!(simulation.len(), 4);
+    }
+}
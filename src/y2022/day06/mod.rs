@@ -0,0 +1,86 @@
+pub mod scanner;
+
+use crate::{explain, Error, Result};
+
+pub fn part1(lines: Vec<String>) -> Result<usize> {
+    marker_end(lines.first().ok_or(Error::EmptyInput)?, 4)
+}
+
+pub fn part2(lines: Vec<String>) -> Result<usize> {
+    marker_end(lines.first().ok_or(Error::EmptyInput)?, 14)
+}
+
+fn marker_end(packet: &str, length: usize) -> Result<usize> {
+    let end = find_marker(packet.as_bytes(), length)
+        .ok_or_else(|| Error::NoSolution(format!("no {} distinct characters in a row", length)))?;
+    explain!("marker", length = length, end = end);
+    Ok(end)
+}
+
+// Number of bytes read when the last `length` of them are all different, or None if that never
+// happens. Any byte value is allowed, and positions are counted in bytes.
+pub fn find_marker(data: &[u8], length: usize) -> Option<usize> {
+    // Only an empty window fits before the first byte
+    if length == 0 {
+        return Some(0);
+    }
+    let mut counters = [0_usize; 256];
+    // Number of bytes of the window that are the same as an earlier byte of the window (AABC
+    // has 1, AAAB has 2)
+    let mut duplicates = 0;
+    for (i, &byte) in data.iter().enumerate() {
+        if i >= length {
+            let outgoing = data[i - length] as usize;
+            counters[outgoing] -= 1;
+            if counters[outgoing] > 0 {
+                duplicates -= 1;
+            }
+        }
+        counters[byte as usize] += 1;
+        if counters[byte as usize] > 1 {
+            duplicates += 1;
+        }
+        if i + 1 >= length && duplicates == 0 {
+            return Some(i + 1);
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        let data = vec![String::from("bvwbjplbgvbhsrlpgdmjqwftvncz")];
+        assert_eq!(part1(data), Ok(5));
+    }
+
+    #[test]
+    fn test_part2() {
+        let data = vec![String::from("mjqjpqmgbljsphdztnvjfqwrcgsmlb")];
+        assert_eq!(part2(data), Ok(19));
+    }
+
+    #[test]
+    fn test_marker_at_end() {
+        let data = vec![String::from("aaabcd")];
+        assert_eq!(part1(data), Ok(6));
+    }
+
+    #[test]
+    fn test_any_byte() {
+        assert_eq!(find_marker(b"AAbB1\xff", 4), Some(5));
+        assert_eq!(find_marker(&[0, 0, 255, 255], 2), Some(3));
+        assert_eq!(find_marker(b"abab", 3), None);
+        assert_eq!(find_marker(b"ab", 3), None);
+        assert_eq!(find_marker(b"ab", 0), Some(0));
+        assert_eq!(find_marker(b"", 0), Some(0));
+
+        // The whole alphabet as one marker
+        let data: Vec<u8> = (0..=255).chain(0..=255).collect();
+        assert_eq!(find_marker(&data, 256), Some(256));
+        assert_eq!(find_marker(&data, 257), None);
+    }
+}
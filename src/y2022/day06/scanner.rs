@@ -0,0 +1,167 @@
+// Finds markers of several lengths in one pass over a stream. Only the run of distinct bytes
+// ending at the current position is tracked: the last `length` bytes are distinct exactly when
+// that run is at least `length` long, so every length is answered at once. Memory doesn't depend
+// on the stream or on the lengths, apart from the list of lengths itself.
+
+use std::io::{self, Read};
+
+const BUFFER_SIZE: usize = 1 << 16;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Mode {
+    // Only the first marker of each length
+    First,
+    // Every position where the last `length` bytes are distinct
+    Every,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Marker {
+    pub length: usize,
+    // Number of bytes read when the marker is complete, like `find_marker` returns
+    pub end: u64,
+}
+
+// Longest run of distinct bytes, the first one if there are several
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Run {
+    pub start: u64, // Starting at 0
+    pub len: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Summary {
+    pub bytes: u64,
+    // End of the first marker of each length, in the order the lengths were given
+    pub first: Vec<Option<u64>>,
+    pub longest: Run,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Scanner {
+    lengths: Vec<usize>,
+    mode: Mode,
+}
+
+impl Scanner {
+    pub fn new(lengths: &[usize]) -> Self {
+        Scanner {
+            lengths: lengths.to_vec(),
+            mode: Mode::First,
+        }
+    }
+
+    pub fn with_mode(mut self, mode: Mode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    // Reads `reader` to the end and calls `on_marker` for every marker found, in stream order.
+    // Markers of different lengths ending at the same position are reported in the order the
+    // lengths were given.
+    pub fn scan<R: Read>(
+        &self,
+        mut reader: R,
+        mut on_marker: impl FnMut(Marker),
+    ) -> io::Result<Summary> {
+        let mut first = vec![None; self.lengths.len()];
+        // Position after the last occurrence of every byte value, 0 if it wasn't seen yet
+        let mut next_after = [0_u64; 256];
+        let mut run_start = 0;
+        let mut longest = Run::default();
+        let mut position = 0;
+
+        let mut report = |end: u64, run: u64, first: &mut Vec<Option<u64>>| {
+            for (i, &length) in self.lengths.iter().enumerate() {
+                if run < length as u64 || (self.mode == Mode::First && first[i].is_some()) {
+                    continue;
+                }
+                first[i].get_or_insert(end);
+                on_marker(Marker { length, end });
+            }
+        };
+        // Empty markers are complete before the first byte
+        report(0, 0, &mut first);
+
+        let mut buffer = vec![0; BUFFER_SIZE];
+        loop {
+            let read = match reader.read(&mut buffer) {
+                Ok(0) => break,
+                Ok(read) => read,
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+                Err(e) => return Err(e),
+            };
+            for &byte in &buffer[..read] {
+                run_start = run_start.max(next_after[byte as usize]);
+                position += 1;
+                next_after[byte as usize] = position;
+
+                let run = position - run_start;
+                if run > longest.len {
+                    longest = Run {
+                        start: run_start,
+                        len: run,
+                    };
+                }
+                report(position, run, &mut first);
+            }
+        }
+
+        Ok(Summary {
+            bytes: position,
+            first,
+            longest,
+        })
+    }
+
+    // Every marker reported by `scan`, kept in memory
+    pub fn markers<R: Read>(&self, reader: R) -> io::Result<(Vec<Marker>, Summary)> {
+        let mut markers = Vec::new();
+        let summary = self.scan(reader, |marker| markers.push(marker))?;
+        Ok((markers, summary))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Returns a single byte per read, so that the scan crosses a read at every byte
+    struct Trickle<'a>(&'a [u8]);
+
+    impl Read for Trickle<'_> {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            let Some((&byte, rest)) = self.0.split_first() else {
+                return Ok(0);
+            };
+            buf[0] = byte;
+            self.0 = rest;
+            Ok(1)
+        }
+    }
+
+    #[test]
+    fn test_first_markers() {
+        let data = b"mjqjpqmgbljsphdztnvjfqwrcgsmlb";
+        let scanner = Scanner::new(&[4, 14, 30, 0]);
+        let summary = scanner.scan(&data[..], |_| ()).unwrap();
+        assert_eq!(summary.bytes, 30);
+        assert_eq!(summary.first, [Some(7), Some(19), None, Some(0)]);
+        assert_eq!(summary.longest, Run { start: 12, len: 18 });
+        assert_eq!(scanner.scan(Trickle(data), |_| ()).unwrap(), summary);
+    }
+
+    #[test]
+    fn test_every_marker() {
+        let scanner = Scanner::new(&[3, 2]).with_mode(Mode::Every);
+        let (markers, summary) = scanner.markers(Trickle(b"aabcb")).unwrap();
+        let ends: Vec<(usize, u64)> = markers.iter().map(|m| (m.length, m.end)).collect();
+        assert_eq!(ends, [(2, 3), (3, 4), (2, 4), (2, 5)]);
+        assert_eq!(summary.first, [Some(4), Some(3)]);
+        assert_eq!(summary.longest, Run { start: 1, len: 3 });
+
+        let (markers, summary) = scanner.markers(io::empty()).unwrap();
+        assert!(markers.is_empty());
+        assert_eq!(summary.longest, Run::default());
+    }
+}
@@ -0,0 +1,283 @@
+// What the puzzle needs from a filesystem, so that the transcript parser and the puzzle queries
+// are written once for every backend. A backend only has to build folders and files, and tell
+// the names, parents, subfolders and files of its folders.
+
+use crate::{explain, Error, Result};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct File {
+    pub name: String,
+    pub size: usize,
+}
+
+pub trait FileSystemQuery {
+    // Handle to a folder, only meaningful for the filesystem that returned it
+    type Folder: Clone;
+
+    fn root(&self) -> Self::Folder;
+
+    fn name(&self, folder: &Self::Folder) -> String;
+
+    // None for the root folder
+    fn parent(&self, folder: &Self::Folder) -> Option<Self::Folder>;
+
+    fn subfolders(&self, folder: &Self::Folder) -> Vec<Self::Folder>;
+
+    // Files directly inside `folder`
+    fn files(&self, folder: &Self::Folder) -> Vec<File>;
+
+    // Total size of the files directly inside `folder`
+    fn files_size(&self, folder: &Self::Folder) -> usize {
+        self.files(folder).iter().map(|file| file.size).sum()
+    }
+
+    fn subfolder(&self, folder: &Self::Folder, name: &str) -> Option<Self::Folder> {
+        self.subfolders(folder)
+            .into_iter()
+            .find(|subfolder| self.name(subfolder) == name)
+    }
+
+    // Size of the files inside `folder` and all of its subfolders
+    fn size(&self, folder: &Self::Folder) -> usize {
+        folder_sizes(self, folder)[0].1
+    }
+}
+
+pub trait FileSystemBuilder: FileSystemQuery + Sized {
+    // Filesystem with only a root folder
+    fn new() -> Self;
+
+    fn add_folder(&mut self, parent: &Self::Folder, name: &str) -> Self::Folder;
+
+    fn add_file(&mut self, folder: &Self::Folder, name: &str, size: usize);
+}
+
+// Replays the commands of a transcript and builds the filesystem they explore
+pub fn parse_transcript<B: FileSystemBuilder>(lines: &[String]) -> Result<B> {
+    let mut i = 1; // First line is `cd /`, can skip
+    let n = lines.len();
+
+    let mut filesystem = B::new();
+    let mut cwd = filesystem.root();
+    // Keep track of the size of every file, if it fits in a usize then so does every folder
+    let mut total_size: usize = 0;
+
+    while i < n {
+        let line = &lines[i];
+
+        if line == "$ ls" {
+            // Read lines until they start with $ (next command)
+            i += 1;
+            while i < n {
+                let line = &lines[i];
+
+                if line.starts_with('$') {
+                    // New command, not data
+                    break;
+                }
+
+                if let Some(name) = line.strip_prefix("dir ") {
+                    // Directory
+                    filesystem.add_folder(&cwd, name);
+                } else {
+                    // File (format: size name)
+                    let (size, name) = line
+                        .split_once(' ')
+                        .and_then(|(size, name)| Some((size.parse().ok()?, name)))
+                        .filter(|(_, name)| !name.is_empty())
+                        .ok_or_else(|| Error::invalid_line(i, "expected `<size> <name>`"))?;
+                    total_size = total_size.checked_add(size).ok_or(Error::Overflow)?;
+                    filesystem.add_file(&cwd, name, size);
+                }
+                i += 1;
+            }
+        } else if let Some(dir_name) = line.strip_prefix("$ cd ") {
+            if dir_name == "/" {
+                cwd = filesystem.root();
+            } else if dir_name == ".." {
+                cwd = filesystem
+                    .parent(&cwd)
+                    .ok_or_else(|| Error::invalid_line(i, "root directory has no parent"))?;
+            } else {
+                cwd = filesystem.subfolder(&cwd, dir_name).ok_or_else(|| {
+                    Error::invalid_line(i, format!("subfolder {} does not exist", dir_name))
+                })?;
+            }
+            i += 1;
+        } else {
+            return Err(Error::invalid_line(i, "expected a command"));
+        }
+    }
+    Ok(filesystem)
+}
+
+// `folder` and every folder below it with their sizes, breadth first. Sizes are summed from the
+// deepest folders up in a single pass, rather than walking the subtree of every folder.
+pub fn folder_sizes<Q: FileSystemQuery + ?Sized>(
+    filesystem: &Q,
+    folder: &Q::Folder,
+) -> Vec<(Q::Folder, usize)> {
+    // Folders with the index of their parent
+    let mut folders: Vec<(Q::Folder, usize)> = vec![(folder.clone(), 0)];
+    let mut i = 0;
+    while i < folders.len() {
+        for subfolder in filesystem.subfolders(&folders[i].0) {
+            folders.push((subfolder, i));
+        }
+        i += 1;
+    }
+
+    let mut sizes: Vec<usize> = folders
+        .iter()
+        .map(|(folder, _)| filesystem.files_size(folder))
+        .collect();
+    for i in (1..folders.len()).rev() {
+        sizes[folders[i].1] += sizes[i];
+    }
+    folders
+        .into_iter()
+        .zip(sizes)
+        .map(|((folder, _), size)| (folder, size))
+        .collect()
+}
+
+// Size of every folder, the root first
+fn all_sizes<Q: FileSystemQuery>(filesystem: &Q) -> Vec<usize> {
+    folder_sizes(filesystem, &filesystem.root())
+        .into_iter()
+        .map(|(folder, size)| {
+            explain!("dir_size", name = filesystem.name(&folder), size = size);
+            size
+        })
+        .collect()
+}
+
+// Part 1: total size of the folders of at most 100000
+pub fn small_folders_size<Q: FileSystemQuery>(filesystem: &Q) -> usize {
+    all_sizes(filesystem)
+        .into_iter()
+        .filter(|&size| size <= 100000)
+        .sum()
+}
+
+// Part 2: size of the smallest folder to delete to get enough free space for the update
+pub fn folder_to_delete<Q: FileSystemQuery>(filesystem: &Q) -> Result<usize> {
+    let sizes = all_sizes(filesystem);
+    let used_size = sizes[0];
+
+    const TOTAL_SIZE: usize = 70000000;
+    const NEEDED_SIZE: usize = 30000000;
+    let free_size: usize = TOTAL_SIZE
+        .checked_sub(used_size)
+        .ok_or_else(|| Error::NoSolution(String::from("filesystem is larger than the disk")))?;
+    let missing_size: usize = NEEDED_SIZE.saturating_sub(free_size);
+
+    // Root folder is always big enough
+    Ok(sizes
+        .into_iter()
+        .filter(|&size| size >= missing_size)
+        .min()
+        .unwrap_or(used_size))
+}
+
+// Example transcript of the puzzle, shared by the tests of every backend and report
+#[cfg(test)]
+pub(crate) fn example() -> Vec<String> {
+    [
+        "$ cd /",
+        "$ ls",
+        "dir a",
+        "14848514 b.txt",
+        "8504156 c.dat",
+        "dir d",
+        "$ cd a",
+        "$ ls",
+        "dir e",
+        "29116 f",
+        "2557 g",
+        "62596 h.lst",
+        "$ cd e",
+        "$ ls",
+        "584 i",
+        "$ cd ..",
+        "$ cd ..",
+        "$ cd d",
+        "$ ls",
+        "4060174 j",
+        "8033020 d.log",
+        "5626152 d.ext",
+        "7214296 k",
+    ]
+    .map(String::from)
+    .to_vec()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::FsTree;
+    use super::*;
+    use crate::y2022::day07_alt::FileSystem;
+
+    // Same checks for every backend
+    fn check_backend<B: FileSystemBuilder>() {
+        let lines = [
+            "$ cd /",
+            "$ ls",
+            "dir a",
+            "14848514 b.txt",
+            "dir d",
+            "$ cd a",
+            "$ ls",
+            "dir e",
+            "29116 f",
+            "$ cd e",
+            "$ ls",
+            "584 i",
+            "$ cd /",
+            "$ cd d",
+            "$ ls",
+            "4060174 j",
+        ]
+        .map(String::from);
+        let filesystem: B = parse_transcript(&lines).unwrap();
+        let root = filesystem.root();
+        assert!(filesystem.parent(&root).is_none());
+        let names: Vec<String> = filesystem
+            .subfolders(&root)
+            .iter()
+            .map(|f| filesystem.name(f))
+            .collect();
+        assert_eq!(names, ["a", "d"]);
+
+        let a = filesystem.subfolder(&root, "a").unwrap();
+        let e = filesystem.subfolder(&a, "e").unwrap();
+        assert_eq!(filesystem.name(&filesystem.parent(&e).unwrap()), "a");
+        assert_eq!(filesystem.files_size(&a), 29116);
+        assert_eq!(filesystem.size(&a), 29700);
+        let sizes: Vec<usize> = folder_sizes(&filesystem, &root)
+            .into_iter()
+            .map(|(_, size)| size)
+            .collect();
+        assert_eq!(sizes, [18938388, 29700, 4060174, 584]);
+        assert_eq!(small_folders_size(&filesystem), 29700 + 584);
+
+        let error = |line: &str| {
+            let lines = [String::from("$ cd /"), String::from(line)];
+            parse_transcript::<B>(&lines).err().unwrap().to_string()
+        };
+        assert_eq!(
+            error("$ cd .."),
+            "Invalid line 2: root directory has no parent"
+        );
+        assert_eq!(
+            error("$ cd x"),
+            "Invalid line 2: subfolder x does not exist"
+        );
+    }
+
+    #[test]
+    fn test_backends() {
+        check_backend::<FsTree>();
+        check_backend::<FileSystem>();
+    }
+}
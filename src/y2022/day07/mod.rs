@@ -0,0 +1,131 @@
+pub mod filesystem;
+pub mod path;
+pub mod report;
+
+use std::{
+    cell::RefCell,
+    rc::{Rc, Weak},
+};
+
+use crate::Result;
+use filesystem::{
+    folder_to_delete, parse_transcript, small_folders_size, File, FileSystemBuilder,
+    FileSystemQuery,
+};
+
+// RefCells get ugly really quickly, probably don't need to use them as extensivly if you refactor
+// code to not use structs and just use a HashMap with (path, size), but was still informative to
+// learn how to use the Rc<RefCell<_>> pattern
+pub struct FsTree {
+    root: Rc<RefCell<FsFolder>>,
+}
+
+pub struct FsFolder {
+    name: String,
+    parent: Option<Weak<RefCell<FsFolder>>>,
+    subfolders: Vec<Rc<RefCell<FsFolder>>>,
+    files: Vec<File>,
+}
+
+impl FsFolder {
+    fn new(name: &str) -> Self {
+        Self {
+            name: name.to_owned(),
+            parent: None,
+            subfolders: Vec::new(),
+            files: Vec::new(),
+        }
+    }
+
+    fn with_parent(name: &str, parent: Weak<RefCell<FsFolder>>) -> Self {
+        Self {
+            name: name.to_owned(),
+            parent: Some(parent),
+            subfolders: Vec::new(),
+            files: Vec::new(),
+        }
+    }
+}
+
+impl FileSystemQuery for FsTree {
+    type Folder = Rc<RefCell<FsFolder>>;
+
+    fn root(&self) -> Self::Folder {
+        self.root.clone()
+    }
+
+    fn name(&self, folder: &Self::Folder) -> String {
+        folder.borrow().name.clone()
+    }
+
+    fn parent(&self, folder: &Self::Folder) -> Option<Self::Folder> {
+        folder.borrow().parent.as_ref().and_then(Weak::upgrade)
+    }
+
+    fn subfolders(&self, folder: &Self::Folder) -> Vec<Self::Folder> {
+        folder.borrow().subfolders.clone()
+    }
+
+    fn files(&self, folder: &Self::Folder) -> Vec<File> {
+        folder.borrow().files.clone()
+    }
+
+    fn subfolder(&self, folder: &Self::Folder, name: &str) -> Option<Self::Folder> {
+        folder
+            .borrow()
+            .subfolders
+            .iter()
+            .find(|&f| f.borrow().name == name)
+            .cloned()
+    }
+}
+
+impl FileSystemBuilder for FsTree {
+    fn new() -> Self {
+        FsTree {
+            root: Rc::new(RefCell::new(FsFolder::new("/"))),
+        }
+    }
+
+    fn add_folder(&mut self, parent: &Self::Folder, name: &str) -> Self::Folder {
+        let folder = Rc::new(RefCell::new(FsFolder::with_parent(
+            name,
+            Rc::downgrade(parent),
+        )));
+        parent.borrow_mut().subfolders.push(folder.clone());
+        folder
+    }
+
+    fn add_file(&mut self, folder: &Self::Folder, name: &str, size: usize) {
+        folder.borrow_mut().files.push(File {
+            name: name.to_owned(),
+            size,
+        });
+    }
+}
+
+pub fn part1(lines: Vec<String>) -> Result<usize> {
+    let filesystem: FsTree = parse_transcript(&lines)?;
+    Ok(small_folders_size(&filesystem))
+}
+
+pub fn part2(lines: Vec<String>) -> Result<usize> {
+    let filesystem: FsTree = parse_transcript(&lines)?;
+    folder_to_delete(&filesystem)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::filesystem::example;
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(example()), Ok(95437));
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(example()), Ok(24933642));
+    }
+}
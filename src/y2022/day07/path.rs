@@ -0,0 +1,319 @@
+// Absolute paths in a reconstructed filesystem, and the queries that use them: looking up a
+// path, listing a folder, and finding files by name pattern or size anywhere in the tree.
+
+use std::{fmt, str::FromStr};
+
+use super::filesystem::{File, FileSystemQuery};
+use crate::{Error, Result};
+
+// Absolute path, stored as the names leading to it from the root folder
+#[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Path {
+    components: Vec<String>,
+}
+
+impl Path {
+    pub fn root() -> Self {
+        Path::default()
+    }
+
+    pub fn components(&self) -> &[String] {
+        &self.components
+    }
+
+    // None for the root folder
+    pub fn name(&self) -> Option<&str> {
+        self.components.last().map(String::as_str)
+    }
+
+    pub fn parent(&self) -> Option<Path> {
+        let (_, components) = self.components.split_last()?;
+        Some(Path {
+            components: components.to_vec(),
+        })
+    }
+
+    pub fn join(&self, name: &str) -> Path {
+        let mut path = self.clone();
+        path.components.push(name.to_owned());
+        path
+    }
+
+    // Number of folders between the root and the end of the path
+    pub fn depth(&self) -> usize {
+        self.components.len()
+    }
+}
+
+// Paths must start with `/`. Empty and `.` components are skipped, and `..` goes back to the
+// parent folder (staying at the root).
+impl FromStr for Path {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let relative = s
+            .strip_prefix('/')
+            .ok_or_else(|| Error::InvalidArgument(format!("path {} is not absolute", s)))?;
+        let mut components = Vec::new();
+        for component in relative.split('/') {
+            match component {
+                "" | "." => (),
+                ".." => {
+                    components.pop();
+                }
+                name => components.push(name.to_owned()),
+            }
+        }
+        Ok(Path { components })
+    }
+}
+
+impl fmt::Display for Path {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.components.is_empty() {
+            return write!(f, "/");
+        }
+        for component in &self.components {
+            write!(f, "/{}", component)?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Entry<F> {
+    Folder(F),
+    File(File),
+}
+
+// Path of a folder, found by going up to the root
+pub fn path_of<Q: FileSystemQuery>(filesystem: &Q, folder: &Q::Folder) -> Path {
+    let mut components = Vec::new();
+    let mut folder = folder.clone();
+    while let Some(parent) = filesystem.parent(&folder) {
+        components.push(filesystem.name(&folder));
+        folder = parent;
+    }
+    components.reverse();
+    Path { components }
+}
+
+pub fn lookup<Q: FileSystemQuery>(filesystem: &Q, path: &Path) -> Option<Entry<Q::Folder>> {
+    let Some((name, folders)) = path.components.split_last() else {
+        return Some(Entry::Folder(filesystem.root()));
+    };
+    let mut folder = filesystem.root();
+    for component in folders {
+        folder = filesystem.subfolder(&folder, component)?;
+    }
+    if let Some(subfolder) = filesystem.subfolder(&folder, name) {
+        return Some(Entry::Folder(subfolder));
+    }
+    filesystem
+        .files(&folder)
+        .into_iter()
+        .find(|file| file.name == *name)
+        .map(Entry::File)
+}
+
+// Subfolders then files of the folder at `path`, None if there is no such folder
+pub fn list<Q: FileSystemQuery>(filesystem: &Q, path: &Path) -> Option<Vec<Entry<Q::Folder>>> {
+    let Some(Entry::Folder(folder)) = lookup(filesystem, path) else {
+        return None;
+    };
+    let mut entries: Vec<Entry<Q::Folder>> = filesystem
+        .subfolders(&folder)
+        .into_iter()
+        .map(Entry::Folder)
+        .collect();
+    entries.extend(filesystem.files(&folder).into_iter().map(Entry::File));
+    Some(entries)
+}
+
+// Every file of the filesystem with its path, depth first, the files of a folder before the
+// ones of its subfolders
+pub struct Files<'a, Q: FileSystemQuery> {
+    filesystem: &'a Q,
+    // Folders left to visit, the next one last
+    folders: Vec<(Path, Q::Folder)>,
+    files: std::vec::IntoIter<(Path, File)>,
+}
+
+pub fn files<Q: FileSystemQuery>(filesystem: &Q) -> Files<'_, Q> {
+    Files {
+        filesystem,
+        folders: vec![(Path::root(), filesystem.root())],
+        files: Vec::new().into_iter(),
+    }
+}
+
+impl<Q: FileSystemQuery> Iterator for Files<'_, Q> {
+    type Item = (Path, File);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(file) = self.files.next() {
+                return Some(file);
+            }
+            let (path, folder) = self.folders.pop()?;
+            for subfolder in self.filesystem.subfolders(&folder).into_iter().rev() {
+                let subfolder_path = path.join(&self.filesystem.name(&subfolder));
+                self.folders.push((subfolder_path, subfolder));
+            }
+            self.files = self
+                .filesystem
+                .files(&folder)
+                .into_iter()
+                .map(|file| (path.join(&file.name), file))
+                .collect::<Vec<_>>()
+                .into_iter();
+        }
+    }
+}
+
+// Files whose path matches `pattern`. `*` matches any part of a name and `?` any character of a
+// name, `**` matches any number of folders. Patterns without a leading `/` can match at any depth,
+// so `*.txt` finds every text file.
+pub fn find_by_glob<Q: FileSystemQuery>(filesystem: &Q, pattern: &str) -> Vec<(Path, File)> {
+    let mut segments: Vec<&str> = Vec::new();
+    if !pattern.starts_with('/') {
+        segments.push("**");
+    }
+    segments.extend(pattern.split('/').filter(|s| !s.is_empty()));
+    files(filesystem)
+        .filter(|(path, _)| matches_path(&segments, path.components()))
+        .collect()
+}
+
+// Files of at least `min_size`, largest first
+pub fn find_by_size<Q: FileSystemQuery>(filesystem: &Q, min_size: usize) -> Vec<(Path, File)> {
+    let mut found: Vec<(Path, File)> = files(filesystem)
+        .filter(|(_, file)| file.size >= min_size)
+        .collect();
+    found.sort_by_key(|(_, file)| std::cmp::Reverse(file.size));
+    found
+}
+
+fn matches_path(segments: &[&str], components: &[String]) -> bool {
+    match segments.split_first() {
+        None => components.is_empty(),
+        Some((&"**", rest)) => (0..=components.len()).any(|i| matches_path(rest, &components[i..])),
+        Some((segment, rest)) => components.split_first().is_some_and(|(name, components)| {
+            matches_name(segment, name) && matches_path(rest, components)
+        }),
+    }
+}
+
+// Wildcard matching that goes back to the last `*` on a mismatch, which is enough since a `*`
+// can always stretch further
+fn matches_name(pattern: &str, name: &str) -> bool {
+    let (pattern, name): (Vec<char>, Vec<char>) =
+        (pattern.chars().collect(), name.chars().collect());
+    let (mut p, mut n) = (0, 0);
+    // Position after the last `*` and the name position it was tried at
+    let mut star: Option<(usize, usize)> = None;
+    while n < name.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                star = Some((p + 1, n));
+                p += 1;
+            }
+            Some(&c) if c == '?' || c == name[n] => {
+                p += 1;
+                n += 1;
+            }
+            _ => match star {
+                Some((after, tried)) => {
+                    // Let the `*` take one more character
+                    star = Some((after, tried + 1));
+                    p = after;
+                    n = tried + 1;
+                }
+                None => return false,
+            },
+        }
+    }
+    pattern[p..].iter().all(|&c| c == '*')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::filesystem::{example, parse_transcript};
+    use super::super::FsTree;
+    use super::*;
+    use crate::y2022::day07_alt::FileSystem;
+
+    fn paths(found: Vec<(Path, File)>) -> Vec<String> {
+        found.iter().map(|(path, _)| path.to_string()).collect()
+    }
+
+    #[test]
+    fn test_path() {
+        let path: Path = "/a//./e/../e/i".parse().unwrap();
+        assert_eq!(path.to_string(), "/a/e/i");
+        assert_eq!(path.name(), Some("i"));
+        assert_eq!(path.parent().unwrap().join("x").to_string(), "/a/e/x");
+        assert_eq!("/..".parse::<Path>(), Ok(Path::root()));
+        assert_eq!(Path::root().to_string(), "/");
+        assert_eq!(
+            "a/e".parse::<Path>(),
+            Err(Error::InvalidArgument(String::from(
+                "path a/e is not absolute"
+            )))
+        );
+    }
+
+    fn check_queries<Q: FileSystemQuery>(filesystem: &Q) {
+        let path = |s: &str| s.parse::<Path>().unwrap();
+        let Some(Entry::Folder(e)) = lookup(filesystem, &path("/a/e")) else {
+            panic!("Missing folder /a/e");
+        };
+        assert_eq!(path_of(filesystem, &e).to_string(), "/a/e");
+        assert!(matches!(
+            lookup(filesystem, &path("/d/k")),
+            Some(Entry::File(file)) if file.name == "k" && file.size == 7214296
+        ));
+        assert!(lookup(filesystem, &path("/a/k")).is_none());
+        assert!(list(filesystem, &path("/b.txt")).is_none());
+        assert_eq!(list(filesystem, &path("/a")).unwrap().len(), 4);
+
+        assert_eq!(
+            paths(files(filesystem).collect()),
+            [
+                "/b.txt", "/c.dat", "/a/f", "/a/g", "/a/h.lst", "/a/e/i", "/d/j", "/d/d.log",
+                "/d/d.ext", "/d/k"
+            ]
+        );
+        assert_eq!(
+            paths(find_by_glob(filesystem, "d.*")),
+            ["/d/d.log", "/d/d.ext"]
+        );
+        assert_eq!(
+            paths(find_by_glob(filesystem, "/?.*")),
+            ["/b.txt", "/c.dat"]
+        );
+        assert_eq!(
+            paths(find_by_glob(filesystem, "/a/**/?")),
+            ["/a/f", "/a/g", "/a/e/i"]
+        );
+        assert_eq!(
+            paths(find_by_size(filesystem, 8000000)),
+            ["/b.txt", "/c.dat", "/d/d.log"]
+        );
+    }
+
+    #[test]
+    fn test_queries() {
+        check_queries(&parse_transcript::<FsTree>(&example()).unwrap());
+        check_queries(&parse_transcript::<FileSystem>(&example()).unwrap());
+    }
+
+    #[test]
+    fn test_matches_name() {
+        assert!(matches_name("*.txt", "b.txt"));
+        assert!(matches_name("a*b*c", "aXbYbZc"));
+        assert!(!matches_name("a*b*c", "aXbYc_"));
+        assert!(matches_name("**", ""));
+        assert!(!matches_name("?", ""));
+    }
+}
@@ -0,0 +1,251 @@
+// Reports on a reconstructed filesystem, like the `tree` and `du -h` commands print them:
+//
+// / (47M)
+// ├── a/ (93K)
+// │   ├── e/ (584)
+// │   │   └── i (584)
+// ...
+//
+// 47M     /
+// 24M     /d
+// 93K     /a
+//
+// Sizes are recursive for folders and written with binary units, rounded up like `du` does.
+
+use std::cmp::Reverse;
+use std::collections::HashMap;
+
+use super::filesystem::{folder_sizes, FileSystemQuery};
+use super::path::{path_of, Path};
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ReportOptions {
+    // Deepest level to show, the root being at 0, all of them if None. It is the depth of the
+    // deepest entries in both reports: `tree` draws the folders at that level without their
+    // content, and `du` lists them.
+    pub max_depth: Option<usize>,
+    // Tree: largest entries to show in each folder. Du: largest folders to show.
+    pub top: Option<usize>,
+}
+
+pub fn human_size(size: usize) -> String {
+    const UNITS: [&str; 6] = ["K", "M", "G", "T", "P", "E"];
+    if size < 1024 {
+        return size.to_string();
+    }
+    let mut scaled = size as f64;
+    let mut unit = 0;
+    while scaled >= 1024.0 * 1024.0 && unit + 1 < UNITS.len() {
+        scaled /= 1024.0;
+        unit += 1;
+    }
+    scaled /= 1024.0;
+    // A single decimal below 10, like `du -h`
+    let tenths = (scaled * 10.0).ceil() / 10.0;
+    if tenths < 10.0 {
+        return format!("{:.1}{}", tenths, UNITS[unit]);
+    }
+    // Rounding up can reach the next unit, like 1048575 which is 1.0M rather than 1024K
+    let rounded = scaled.ceil();
+    if rounded >= 1024.0 && unit + 1 < UNITS.len() {
+        format!("1.0{}", UNITS[unit + 1])
+    } else {
+        format!("{}{}", rounded, UNITS[unit])
+    }
+}
+
+// Recursive size of every folder
+fn sizes_by_path<Q: FileSystemQuery>(filesystem: &Q) -> Vec<(Path, usize)> {
+    folder_sizes(filesystem, &filesystem.root())
+        .into_iter()
+        .map(|(folder, size)| (path_of(filesystem, &folder), size))
+        .collect()
+}
+
+// Entry of a folder in the tree, with the handle of subfolders
+struct Entry<F> {
+    name: String,
+    folder: Option<F>,
+    size: usize,
+}
+
+// Folder being drawn: its entries left to draw, and how many were left out by `top`
+struct Frame<F> {
+    path: Path,
+    prefix: String,
+    entries: std::vec::IntoIter<Entry<F>>,
+    hidden: usize,
+}
+
+fn frame<Q: FileSystemQuery>(
+    filesystem: &Q,
+    sizes: &HashMap<Path, usize>,
+    folder: &Q::Folder,
+    path: Path,
+    prefix: String,
+    top: Option<usize>,
+) -> Frame<Q::Folder> {
+    let mut entries: Vec<Entry<Q::Folder>> = filesystem
+        .subfolders(folder)
+        .into_iter()
+        .map(|subfolder| {
+            let name = filesystem.name(&subfolder);
+            Entry {
+                size: sizes[&path.join(&name)],
+                name,
+                folder: Some(subfolder),
+            }
+        })
+        .chain(filesystem.files(folder).into_iter().map(|file| Entry {
+            name: file.name,
+            folder: None,
+            size: file.size,
+        }))
+        .collect();
+    // By name like `tree`, or the largest first when only some of them are drawn
+    entries.sort_by(|a, b| a.name.cmp(&b.name));
+    let mut hidden = 0;
+    if let Some(top) = top {
+        entries.sort_by_key(|entry| Reverse(entry.size));
+        hidden = entries.len().saturating_sub(top);
+        entries.truncate(top);
+    }
+    Frame {
+        path,
+        prefix,
+        entries: entries.into_iter(),
+        hidden,
+    }
+}
+
+pub fn tree<Q: FileSystemQuery>(filesystem: &Q, options: &ReportOptions) -> Vec<String> {
+    let sizes: HashMap<Path, usize> = sizes_by_path(filesystem).into_iter().collect();
+    let mut lines = vec![format!("/ ({})", human_size(sizes[&Path::root()]))];
+    let is_shown = |path: &Path| options.max_depth.is_none_or(|depth| path.depth() < depth);
+
+    let mut frames = Vec::new();
+    if is_shown(&Path::root()) {
+        let root = filesystem.root();
+        frames.push(frame(
+            filesystem,
+            &sizes,
+            &root,
+            Path::root(),
+            String::new(),
+            options.top,
+        ));
+    }
+    while let Some(current) = frames.last_mut() {
+        let Some(entry) = current.entries.next() else {
+            if current.hidden > 0 {
+                lines.push(format!("{}└── {} more", current.prefix, current.hidden));
+            }
+            frames.pop();
+            continue;
+        };
+        let last = current.entries.len() == 0 && current.hidden == 0;
+        let (connector, indent) = if last {
+            ("└── ", "    ")
+        } else {
+            ("├── ", "│   ")
+        };
+        let slash = if entry.folder.is_some() { "/" } else { "" };
+        lines.push(format!(
+            "{}{}{}{} ({})",
+            current.prefix,
+            connector,
+            entry.name,
+            slash,
+            human_size(entry.size)
+        ));
+
+        if let Some(subfolder) = entry.folder {
+            let path = current.path.join(&entry.name);
+            if is_shown(&path) {
+                let prefix = format!("{}{}", current.prefix, indent);
+                frames.push(frame(
+                    filesystem,
+                    &sizes,
+                    &subfolder,
+                    path,
+                    prefix,
+                    options.top,
+                ));
+            }
+        }
+    }
+    lines
+}
+
+// Folders sorted by decreasing size, then by path
+pub fn du<Q: FileSystemQuery>(filesystem: &Q, options: &ReportOptions) -> Vec<String> {
+    let mut sizes: Vec<(Path, usize)> = sizes_by_path(filesystem)
+        .into_iter()
+        .filter(|(path, _)| options.max_depth.is_none_or(|depth| path.depth() <= depth))
+        .collect();
+    sizes.sort_by(|(p1, s1), (p2, s2)| s2.cmp(s1).then_with(|| p1.cmp(p2)));
+    sizes.truncate(options.top.unwrap_or(usize::MAX));
+    sizes
+        .into_iter()
+        .map(|(path, size)| format!("{}\t{}", human_size(size), path))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::filesystem::{example, parse_transcript};
+    use super::super::FsTree;
+    use super::*;
+    use crate::y2022::day07_alt::FileSystem;
+
+    #[test]
+    fn test_human_size() {
+        assert_eq!(human_size(584), "584");
+        assert_eq!(human_size(1024), "1.0K");
+        assert_eq!(human_size(1025), "1.1K");
+        assert_eq!(human_size(94853), "93K");
+        assert_eq!(human_size(48381165), "47M");
+        assert_eq!(human_size(1048575), "1.0M");
+        assert_eq!(human_size(1024 * 1024), "1.0M");
+        assert_eq!(human_size(1024 * 1024 - 1024), "1023K");
+        assert_eq!(human_size(usize::MAX), "16E");
+    }
+
+    #[test]
+    fn test_reports() {
+        let tree_lines = [
+            "/ (47M)",
+            "├── a/ (93K)",
+            "│   ├── e/ (584)",
+            "│   │   └── i (584)",
+            "│   ├── f (29K)",
+            "│   ├── g (2.5K)",
+            "│   └── h.lst (62K)",
+            "├── b.txt (15M)",
+            "├── c.dat (8.2M)",
+            "└── d/ (24M)",
+            "    ├── d.ext (5.4M)",
+            "    ├── d.log (7.7M)",
+            "    ├── j (3.9M)",
+            "    └── k (6.9M)",
+        ];
+        let top = ReportOptions {
+            max_depth: Some(1),
+            top: Some(2),
+        };
+        let top_lines = ["/ (47M)", "├── d/ (24M)", "├── b.txt (15M)", "└── 2 more"];
+        let du_lines = ["47M\t/", "24M\t/d"];
+
+        let filesystem: FsTree = parse_transcript(&example()).unwrap();
+        assert_eq!(tree(&filesystem, &ReportOptions::default()), tree_lines);
+        assert_eq!(tree(&filesystem, &top), top_lines);
+        assert_eq!(du(&filesystem, &top), du_lines);
+        let filesystem: FileSystem = parse_transcript(&example()).unwrap();
+        assert_eq!(tree(&filesystem, &ReportOptions::default()), tree_lines);
+        assert_eq!(tree(&filesystem, &top), top_lines);
+        assert_eq!(du(&filesystem, &top), du_lines);
+
+        let all = du(&filesystem, &ReportOptions::default());
+        assert_eq!(all, ["47M\t/", "24M\t/d", "93K\t/a", "584\t/a/e"]);
+    }
+}
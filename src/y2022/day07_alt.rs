@@ -0,0 +1,152 @@
+// Re-implementation of day07 without the use of Rc<RefCell>>. This is done by having a central
+// struct that owns the Folders and files, and each filesystem item being a handle to one of the
+// indices of that central struct. This should remove the overhead added by the runtime borrow
+// checking done by RefCells.
+
+use super::day07::filesystem::{
+    folder_to_delete, parse_transcript, small_folders_size, File, FileSystemBuilder,
+    FileSystemQuery,
+};
+use crate::Result;
+
+type FolderHandle = usize;
+pub struct FileSystem {
+    folders: Vec<FsFolder>,
+}
+
+impl FileSystem {
+    fn folder(&self, folder_handle: FolderHandle) -> &FsFolder {
+        self.folders
+            .get(folder_handle)
+            .expect("Invalid folder handle.")
+    }
+
+    fn folder_mut(&mut self, folder_handle: FolderHandle) -> &mut FsFolder {
+        self.folders
+            .get_mut(folder_handle)
+            .expect("Invalid folder handle.")
+    }
+}
+
+impl FileSystemQuery for FileSystem {
+    type Folder = FolderHandle;
+
+    fn root(&self) -> FolderHandle {
+        0 // Root directory should always have a zero handle
+    }
+
+    fn name(&self, folder_handle: &FolderHandle) -> String {
+        self.folder(*folder_handle).name.clone()
+    }
+
+    fn parent(&self, folder_handle: &FolderHandle) -> Option<FolderHandle> {
+        // The root folder is its own parent
+        Some(self.folder(*folder_handle).parent).filter(|_| *folder_handle != 0)
+    }
+
+    fn subfolders(&self, parent_handle: &FolderHandle) -> Vec<FolderHandle> {
+        self.folder(*parent_handle).subfolders.clone()
+    }
+
+    fn files(&self, folder_handle: &FolderHandle) -> Vec<File> {
+        self.folder(*folder_handle).files.clone()
+    }
+
+    fn files_size(&self, folder_handle: &FolderHandle) -> usize {
+        self.folder(*folder_handle).files_size
+    }
+
+    fn subfolder(&self, parent_handle: &FolderHandle, child_name: &str) -> Option<FolderHandle> {
+        self.folder(*parent_handle)
+            .subfolders
+            .iter()
+            .find(|&&c_handle| self.folder(c_handle).name == child_name)
+            .copied()
+    }
+}
+
+impl FileSystemBuilder for FileSystem {
+    fn new() -> Self {
+        let root_folder = FsFolder {
+            name: String::from("/"),
+            files_size: 0,
+            files: Vec::new(),
+            subfolders: Vec::new(),
+            parent: 0,
+        };
+
+        Self {
+            folders: vec![root_folder],
+        }
+    }
+
+    fn add_folder(&mut self, parent_handle: &FolderHandle, child_name: &str) -> FolderHandle {
+        let child_folder = FsFolder::with_parent(child_name, *parent_handle);
+        self.folders.push(child_folder);
+        let child_handle = self.folders.len() - 1;
+        self.folder_mut(*parent_handle).add_subfolder(child_handle);
+        child_handle
+    }
+
+    fn add_file(&mut self, folder_handle: &FolderHandle, name: &str, file_size: usize) {
+        self.folder_mut(*folder_handle).add_file(File {
+            name: name.to_owned(),
+            size: file_size,
+        });
+    }
+}
+
+struct FsFolder {
+    name: String,
+    files_size: usize, // Size of files contained in folder
+    files: Vec<File>,
+    subfolders: Vec<FolderHandle>,
+    parent: FolderHandle,
+}
+
+impl FsFolder {
+    fn with_parent(name: &str, parent: FolderHandle) -> Self {
+        FsFolder {
+            name: name.to_owned(),
+            files_size: 0,
+            files: Vec::new(),
+            subfolders: Vec::new(),
+            parent,
+        }
+    }
+
+    fn add_subfolder(&mut self, subfolder: FolderHandle) {
+        self.subfolders.push(subfolder);
+    }
+
+    fn add_file(&mut self, file: File) {
+        self.files_size += file.size;
+        self.files.push(file);
+    }
+}
+
+pub fn part1(lines: Vec<String>) -> Result<usize> {
+    let filesystem: FileSystem = parse_transcript(&lines)?;
+    Ok(small_folders_size(&filesystem))
+}
+
+pub fn part2(lines: Vec<String>) -> Result<usize> {
+    let filesystem: FileSystem = parse_transcript(&lines)?;
+    folder_to_delete(&filesystem)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::y2022::day07::filesystem::example;
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(example()), Ok(95437));
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(example()), Ok(24933642));
+    }
+}
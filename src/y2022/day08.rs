@@ -1,13 +1,30 @@
-pub fn part1(lines: Vec<String>) -> usize {
-    let n = lines.len();
-    let m = lines[0].len();
+use crate::{explain, Error, Result};
 
-    let mut grid = vec![vec![0; m]; n];
+fn parse_grid(lines: &[String]) -> Result<Vec<Vec<u32>>> {
+    let m = lines.first().ok_or(Error::EmptyInput)?.len();
+    if m == 0 {
+        return Err(Error::invalid_line(0, "empty row"));
+    }
+
+    let mut grid = Vec::with_capacity(lines.len());
     for (row, line) in lines.iter().enumerate() {
-        for (col, c) in line.chars().enumerate() {
-            grid[row][col] = c.to_digit(10).unwrap();
+        let heights = line
+            .chars()
+            .map(|c| c.to_digit(10))
+            .collect::<Option<Vec<u32>>>()
+            .ok_or_else(|| Error::invalid_line(row, "tree heights must be digits"))?;
+        if heights.len() != m {
+            return Err(Error::invalid_line(row, "rows have different lengths"));
         }
+        grid.push(heights);
     }
+    Ok(grid)
+}
+
+pub fn part1(lines: Vec<String>) -> Result<usize> {
+    let grid = parse_grid(&lines)?;
+    let n = grid.len();
+    let m = grid[0].len();
 
     // Calculate tallest in every direction for each coord
     let mut memo = vec![vec![0; m]; n];
@@ -50,30 +67,29 @@ pub fn part1(lines: Vec<String>) -> usize {
     }
 
     // Initialize variable such that we count every tree on the edge as visible
+    if n == 1 || m == 1 {
+        // Every tree is on the edge
+        return Ok(n * m);
+    }
     let mut visible_cnt = 2 * n + 2 * m - 4;
 
     // Iterate over trees not on the edge
     for row in 1..n - 1 {
         for col in 1..m - 1 {
             if grid[row][col] > memo[row][col] {
+                explain!("visible", row = row, col = col, height = grid[row][col]);
                 visible_cnt += 1;
             }
         }
     }
 
-    visible_cnt
+    Ok(visible_cnt)
 }
 
-pub fn part2(lines: Vec<String>) -> usize {
-    let n = lines.len();
-    let m = lines[0].len();
-
-    let mut grid = vec![vec![0; m]; n];
-    for (row, line) in lines.iter().enumerate() {
-        for (col, c) in line.chars().enumerate() {
-            grid[row][col] = c.to_digit(10).unwrap();
-        }
-    }
+pub fn part2(lines: Vec<String>) -> Result<usize> {
+    let grid = parse_grid(&lines)?;
+    let n = grid.len();
+    let m = grid[0].len();
 
     // Ignore all positions on edge, as they will get a score multiplied by 0
     let mut max_score = 0;
@@ -102,11 +118,14 @@ pub fn part2(lines: Vec<String>) -> usize {
                 }
                 score *= distance - 1;
             }
+            if score > max_score {
+                explain!("best_view", row = row, col = col, score = score);
+            }
             max_score = std::cmp::max(max_score, score);
         }
     }
 
-    max_score
+    Ok(max_score)
 }
 
 #[cfg(test)]
@@ -118,7 +137,7 @@ mod tests {
         let data = ["30373", "25512", "65332", "33549", "35390"]
             .map(String::from)
             .to_vec();
-        assert_eq!(part1(data), 21);
+        assert_eq!(part1(data), Ok(21));
     }
 
     #[test]
@@ -126,6 +145,6 @@ mod tests {
         let data = ["30373", "25512", "65332", "33549", "35390"]
             .map(String::from)
             .to_vec();
-        assert_eq!(part2(data), 8);
+        assert_eq!(part2(data), Ok(8));
     }
 }
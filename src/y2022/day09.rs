@@ -1,14 +1,38 @@
 use std::collections::HashSet;
 
-pub fn part1(lines: Vec<String>) -> usize {
+use crate::{explain, Error, Result};
+
+// Motions are simulated one step at a time, so longer ones are rejected rather than spinning on
+// inputs like `R 300000000`. Puzzle inputs don't go past 20.
+const MAX_STEPS: usize = 10000;
+
+fn parse_motion(index: usize, line: &str) -> Result<(&str, usize)> {
+    // Line should have format: direction steps
+    match line.split_once(' ') {
+        Some((dir @ ("U" | "D" | "L" | "R"), n)) => {
+            let n: usize = n
+                .parse()
+                .map_err(|_| Error::invalid_line(index, "invalid number of steps"))?;
+            if n > MAX_STEPS {
+                return Err(Error::invalid_line(
+                    index,
+                    format!("more than {} steps", MAX_STEPS),
+                ));
+            }
+            Ok((dir, n))
+        }
+        _ => Err(Error::invalid_line(index, "expected `<U|D|L|R> <steps>`")),
+    }
+}
+
+pub fn part1(lines: Vec<String>) -> Result<usize> {
     let mut h_pos = (0isize, 0isize);
     let mut t_pos = (0isize, 0isize);
     let mut visited: HashSet<(isize, isize)> = HashSet::new();
     visited.insert(t_pos);
-    for line in lines {
-        let mut iter = line.split(' ');
-        let dir = iter.next().unwrap();
-        let n: usize = iter.next().unwrap().parse().unwrap();
+    let mut step = 0;
+    for (i, line) in lines.iter().enumerate() {
+        let (dir, n) = parse_motion(i, line)?;
 
         for _ in 0..n {
             // Move head based on input, then move tail if needed
@@ -51,10 +75,17 @@ pub fn part1(lines: Vec<String>) -> usize {
                 }
             }
 
+            step += 1;
+            explain!(
+                "step",
+                step = step,
+                head = format!("({},{})", h_pos.0, h_pos.1),
+                tail = format!("({},{})", t_pos.0, t_pos.1),
+            );
             visited.insert(t_pos);
         }
     }
-    visited.len()
+    Ok(visited.len())
 }
 
 // PART 2 functions (could be used to solve part1 as well if wanted)
@@ -92,15 +123,14 @@ fn move_knot(head: (isize, isize), curr: (isize, isize)) -> (isize, isize) {
     }
     new_pos
 }
-pub fn part2(lines: Vec<String>) -> usize {
+pub fn part2(lines: Vec<String>) -> Result<usize> {
     let mut knots = [(0, 0); 10];
     let mut visited: HashSet<(isize, isize)> = HashSet::new();
     visited.insert(knots[9]);
 
-    for line in lines {
-        let mut iter = line.split(' ');
-        let dir = iter.next().unwrap();
-        let n: usize = iter.next().unwrap().parse().unwrap();
+    let mut step = 0;
+    for (i, line) in lines.iter().enumerate() {
+        let (dir, n) = parse_motion(i, line)?;
 
         for _ in 0..n {
             // Move head (0th knot) from input
@@ -117,12 +147,19 @@ pub fn part2(lines: Vec<String>) -> usize {
                 knots[i] = move_knot(knots[i - 1], knots[i])
             }
 
+            step += 1;
+            explain!(
+                "step",
+                step = step,
+                head = format!("({},{})", knots[0].0, knots[0].1),
+                tail = format!("({},{})", knots[9].0, knots[9].1),
+            );
             // Save location of last knot
             visited.insert(knots[9]);
         }
     }
 
-    visited.len()
+    Ok(visited.len())
 }
 
 #[cfg(test)]
@@ -142,7 +179,11 @@ R 2"
         .split('\n')
         .map(String::from)
         .collect();
-        assert_eq!(part1(data), 13);
+        assert_eq!(part1(data), Ok(13));
+        assert_eq!(
+            part1(vec![String::from("R 300000000")]),
+            Err(Error::invalid_line(0, "more than 10000 steps"))
+        );
     }
 
     #[test]
@@ -159,7 +200,7 @@ R 2"
         .split('\n')
         .map(String::from)
         .collect();
-        assert_eq!(part2(data), 1);
+        assert_eq!(part2(data), Ok(1));
 
         // Second example
         let data: Vec<String> = "R 5
@@ -173,6 +214,6 @@ U 20"
             .split('\n')
             .map(String::from)
             .collect();
-        assert_eq!(part2(data), 36);
+        assert_eq!(part2(data), Ok(36));
     }
 }
@@ -0,0 +1,10 @@
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day07_alt;
+pub mod day08;
+pub mod day09;
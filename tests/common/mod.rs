@@ -0,0 +1,91 @@
+// Small helpers shared by the integration tests. The crate has no dependencies, so instead of
+// pulling in a property testing framework we use a seeded PRNG and a greedy shrinker.
+
+#![allow(dead_code)]
+
+use std::fmt::Debug;
+use std::panic::{self, AssertUnwindSafe};
+
+pub use aoc2022::rng::Rng;
+
+fn env_or(name: &str, default: u64) -> u64 {
+    std::env::var(name)
+        .ok()
+        .and_then(|s| s.parse().ok())
+        .unwrap_or(default)
+}
+
+// Runs `prop` against `AOC_PROP_CASES` (default 2000) generated cases. On failure, the case is
+// greedily shrunk using the candidates returned by `shrink` until no smaller candidate fails,
+// then the test panics with the minimal reproducer and the seed needed to replay it.
+pub fn check<T, G, S, P>(name: &str, gen: G, shrink: S, prop: P)
+where
+    T: Clone + Debug,
+    G: Fn(&mut Rng) -> T,
+    S: Fn(&T) -> Vec<T>,
+    P: Fn(&T) -> bool,
+{
+    let seed = env_or("AOC_PROP_SEED", 0x00c2022);
+    let cases = env_or("AOC_PROP_CASES", 2000);
+    let mut rng = Rng::new(seed);
+
+    let fails = |case: &T| !panic::catch_unwind(AssertUnwindSafe(|| prop(case))).unwrap_or(false);
+
+    for i in 0..cases {
+        let case = gen(&mut rng);
+        if !fails(&case) {
+            continue;
+        }
+
+        let mut minimal = case.clone();
+        'shrinking: loop {
+            for candidate in shrink(&minimal) {
+                if fails(&candidate) {
+                    minimal = candidate;
+                    continue 'shrinking;
+                }
+            }
+            break;
+        }
+        panic!(
+            "{} failed on case {} (seed {:#x}).\nOriginal: {:?}\nMinimal: {:?}",
+            name, i, seed, case, minimal
+        );
+    }
+}
+
+// Shrink candidates for a vector: remove each element, then shrink each element in place
+pub fn shrink_vec<T: Clone>(items: &[T], shrink_item: impl Fn(&T) -> Vec<T>) -> Vec<Vec<T>> {
+    let mut candidates = Vec::new();
+    if items.len() > 1 {
+        candidates.push(items[..items.len() / 2].to_vec());
+        candidates.push(items[items.len() / 2..].to_vec());
+    }
+    for i in 0..items.len() {
+        let mut smaller = items.to_vec();
+        smaller.remove(i);
+        candidates.push(smaller);
+    }
+    for (i, item) in items.iter().enumerate() {
+        for shrunk in shrink_item(item) {
+            let mut smaller = items.to_vec();
+            smaller[i] = shrunk;
+            candidates.push(smaller);
+        }
+    }
+    candidates
+}
+
+pub fn shrink_num(n: u64) -> Vec<u64> {
+    let mut candidates = Vec::new();
+    for c in [0, n / 2, n.saturating_sub(1)] {
+        if c < n && !candidates.contains(&c) {
+            candidates.push(c);
+        }
+    }
+    candidates
+}
+
+pub fn to_lines(lines: &[&str]) -> Vec<String> {
+    lines.iter().map(|&s| String::from(s)).collect()
+}
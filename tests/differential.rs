@@ -0,0 +1,753 @@
+// Differential tests: every day is checked against a naive, brute-force reference implementation
+// on thousands of generated puzzle inputs. Disagreements are shrunk to a minimal reproducer.
+
+mod common;
+
+use std::collections::{HashMap, HashSet};
+
+use aoc2022::testing::day03::{group, rucksack, LETTERS};
+use common::{check, shrink_num, shrink_vec, Rng};
+
+// Day 01
+
+fn day01_render(elves: &[Vec<u64>]) -> Vec<String> {
+    elves
+        .iter()
+        .map(|elf| {
+            elf.iter()
+                .map(u64::to_string)
+                .collect::<Vec<_>>()
+                .join("\n")
+        })
+        .collect::<Vec<_>>()
+        .join("\n\n")
+        .split('\n')
+        .map(String::from)
+        .collect()
+}
+
+fn day01_reference(lines: &[String]) -> Vec<u64> {
+    let mut totals: Vec<u64> = lines
+        .split(|l| l.is_empty())
+        .map(|elf| elf.iter().map(|l| l.parse::<u64>().unwrap()).sum())
+        .collect();
+    totals.sort_unstable_by(|a, b| b.cmp(a));
+    totals
+}
+
+#[test]
+fn day01_matches_reference() {
+    check(
+        "day01",
+        |rng: &mut Rng| {
+            let elves = rng.range(1, 30);
+            (0..elves)
+                .map(|_| (0..rng.range(1, 5)).map(|_| rng.range(1, 99999)).collect())
+                .collect::<Vec<Vec<u64>>>()
+        },
+        |elves| {
+            shrink_vec(elves, |elf| shrink_vec(elf, |&n| shrink_num(n)))
+                .into_iter()
+                .filter(|e| !e.is_empty() && e.iter().all(|elf| !elf.is_empty()))
+                .collect()
+        },
+        |elves| {
+            let lines = day01_render(elves);
+            let totals = day01_reference(&lines);
+            aoc2022::y2022::day01::part1(lines.clone()) == Ok(totals[0])
+                && aoc2022::y2022::day01::part2(lines) == Ok(totals.iter().take(3).sum::<u64>())
+        },
+    );
+}
+
+// Day 02
+
+fn day02_beats(a: u8, b: u8) -> bool {
+    // 0 = Rock, 1 = Paper, 2 = Scissors
+    matches!((a, b), (0, 2) | (1, 0) | (2, 1))
+}
+
+fn day02_outcome(me: u8, opp: u8) -> u32 {
+    if me == opp {
+        3
+    } else if day02_beats(me, opp) {
+        6
+    } else {
+        0
+    }
+}
+
+#[test]
+fn day02_matches_reference() {
+    check(
+        "day02",
+        |rng: &mut Rng| {
+            (0..rng.range(0, 40))
+                .map(|_| (rng.below(3) as u8, rng.below(3) as u8))
+                .collect::<Vec<(u8, u8)>>()
+        },
+        |rounds| shrink_vec(rounds, |_| Vec::new()),
+        |rounds| {
+            let lines: Vec<String> = rounds
+                .iter()
+                .map(|&(a, b)| format!("{} {}", (b'A' + a) as char, (b'X' + b) as char))
+                .collect();
+
+            let part1: u32 = rounds
+                .iter()
+                .map(|&(opp, me)| me as u32 + 1 + day02_outcome(me, opp))
+                .sum();
+            // Part 2: try every pick until one gives the requested outcome
+            let part2: u32 = rounds
+                .iter()
+                .map(|&(opp, wanted)| {
+                    let me = (0..3)
+                        .find(|&me| day02_outcome(me, opp) == wanted as u32 * 3)
+                        .unwrap();
+                    me as u32 + 1 + wanted as u32 * 3
+                })
+                .sum();
+
+            aoc2022::y2022::day02::part1(lines.clone()) == Ok(part1)
+                && aoc2022::y2022::day02::part2(lines) == Ok(part2)
+        },
+    );
+}
+
+// Day 03
+
+fn day03_priority(c: char) -> u32 {
+    LETTERS.iter().position(|&l| l as char == c).unwrap() as u32 + 1
+}
+
+#[test]
+fn day03_part1_matches_reference() {
+    check(
+        "day03 part1",
+        |rng: &mut Rng| {
+            (0..rng.range(0, 20))
+                .map(|_| rucksack(rng, 1..=12))
+                .collect()
+        },
+        |lines: &Vec<String>| shrink_vec(lines, |_| Vec::new()),
+        |lines| {
+            let expected: u32 = lines
+                .iter()
+                .map(|l| {
+                    let (h1, h2) = l.split_at(l.len() / 2);
+                    let common = h1.chars().find(|&c| h2.contains(c)).unwrap();
+                    day03_priority(common)
+                })
+                .sum();
+            aoc2022::y2022::day03::part1(lines.clone()) == Ok(expected)
+        },
+    );
+}
+
+#[test]
+fn day03_part2_matches_reference() {
+    check(
+        "day03 part2",
+        |rng: &mut Rng| (0..rng.range(0, 8)).map(|_| group(rng, 2..=21)).collect(),
+        |groups: &Vec<Vec<String>>| shrink_vec(groups, |_| Vec::new()),
+        |groups| {
+            let expected: u32 = groups
+                .iter()
+                .map(|g| {
+                    let badge = LETTERS
+                        .iter()
+                        .map(|&c| c as char)
+                        .find(|&c| g.iter().all(|l| l.contains(c)))
+                        .unwrap();
+                    day03_priority(badge)
+                })
+                .sum();
+            aoc2022::y2022::day03::part2(groups.concat()) == Ok(expected)
+        },
+    );
+}
+
+// Day 04
+
+#[test]
+fn day04_matches_reference() {
+    check(
+        "day04",
+        |rng: &mut Rng| {
+            (0..rng.range(0, 30))
+                .map(|_| {
+                    let mut range = || {
+                        let start = rng.range(1, 20);
+                        (start, rng.range(start, 20))
+                    };
+                    (range(), range())
+                })
+                .collect::<Vec<_>>()
+        },
+        |pairs| shrink_vec(pairs, |_| Vec::new()),
+        |pairs| {
+            let lines: Vec<String> = pairs
+                .iter()
+                .map(|((a, b), (c, d))| format!("{}-{},{}-{}", a, b, c, d))
+                .collect();
+
+            let sets: Vec<(HashSet<u64>, HashSet<u64>)> = pairs
+                .iter()
+                .map(|&((a, b), (c, d))| ((a..=b).collect(), (c..=d).collect()))
+                .collect();
+            let contained = sets
+                .iter()
+                .filter(|(r1, r2)| r1.is_subset(r2) || r2.is_subset(r1))
+                .count() as u32;
+            let overlapping = sets.iter().filter(|(r1, r2)| !r1.is_disjoint(r2)).count() as u32;
+
+            aoc2022::y2022::day04::part1(lines.clone()) == Ok(contained)
+                && aoc2022::y2022::day04::part2(lines) == Ok(overlapping)
+        },
+    );
+}
+
+// Day 05
+
+#[derive(Clone, Debug)]
+struct Day05Case {
+    stacks: Vec<Vec<char>>,
+    procedures: Vec<(usize, usize, usize)>,
+}
+
+impl Day05Case {
+    fn render(&self) -> Vec<String> {
+        let height = self.stacks.iter().map(Vec::len).max().unwrap_or(0);
+        let mut lines = Vec::new();
+        for level in (0..height).rev() {
+            let row: Vec<String> = self
+                .stacks
+                .iter()
+                .map(|s| match s.get(level) {
+                    Some(c) => format!("[{}]", c),
+                    None => String::from("   "),
+                })
+                .collect();
+            lines.push(row.join(" "));
+        }
+        let numbers: Vec<String> = (1..=self.stacks.len())
+            .map(|i| format!(" {} ", i))
+            .collect();
+        lines.push(numbers.join(" "));
+        lines.push(String::new());
+        for &(quantity, origin, destination) in &self.procedures {
+            lines.push(format!(
+                "move {} from {} to {}",
+                quantity, origin, destination
+            ));
+        }
+        lines
+    }
+
+    // Runs the procedures naively, returns None if a procedure can't be applied
+    fn simulate(&self, batch: bool) -> Option<Vec<Vec<char>>> {
+        let mut stacks = self.stacks.clone();
+        for &(quantity, origin, destination) in &self.procedures {
+            if origin == destination || quantity > stacks[origin - 1].len() {
+                return None;
+            }
+            let mut lifted = Vec::new();
+            for _ in 0..quantity {
+                lifted.push(stacks[origin - 1].pop().unwrap());
+            }
+            if batch {
+                lifted.reverse();
+            }
+            stacks[destination - 1].extend(lifted);
+        }
+        Some(stacks)
+    }
+
+    fn answer(&self, batch: bool) -> Option<String> {
+        let stacks = self.simulate(batch)?;
+        stacks.iter().map(|s| s.last().copied()).collect()
+    }
+}
+
+fn day05_case(rng: &mut Rng) -> Day05Case {
+    let n = rng.range(1, 9) as usize;
+    let stacks: Vec<Vec<char>> = (0..n)
+        .map(|_| {
+            (0..rng.range(1, 6))
+                .map(|_| *rng.pick(&LETTERS[26..]) as char)
+                .collect()
+        })
+        .collect();
+
+    // Only the stack heights matter to keep procedures valid
+    let mut heights: Vec<usize> = stacks.iter().map(Vec::len).collect();
+    let mut procedures = Vec::new();
+    if n > 1 {
+        for _ in 0..rng.range(0, 15) {
+            let origin = rng.below(n);
+            if heights[origin] == 0 {
+                continue;
+            }
+            let destination = (origin + 1 + rng.below(n - 1)) % n;
+            let quantity = rng.range(1, heights[origin] as u64) as usize;
+            heights[origin] -= quantity;
+            heights[destination] += quantity;
+            procedures.push((quantity, origin + 1, destination + 1));
+        }
+    }
+    Day05Case { stacks, procedures }
+}
+
+fn day05_shrink(case: &Day05Case) -> Vec<Day05Case> {
+    let mut candidates = Vec::new();
+    for procedures in shrink_vec(&case.procedures, |&(q, o, d)| {
+        shrink_num(q as u64)
+            .into_iter()
+            .filter(|&q| q > 0)
+            .map(|q| (q as usize, o, d))
+            .collect()
+    }) {
+        candidates.push(Day05Case {
+            stacks: case.stacks.clone(),
+            procedures,
+        });
+    }
+    for (i, stack) in case.stacks.iter().enumerate() {
+        for j in 0..stack.len() {
+            let mut stacks = case.stacks.clone();
+            stacks[i].remove(j);
+            candidates.push(Day05Case {
+                stacks,
+                procedures: case.procedures.clone(),
+            });
+        }
+    }
+    candidates
+        .into_iter()
+        .filter(|c| c.simulate(false).is_some())
+        .collect()
+}
+
+#[test]
+fn day05_matches_reference() {
+    check("day05", day05_case, day05_shrink, |case| {
+        let lines = case.render();
+        // The puzzle always leaves a crate on top of every stack
+        match (case.answer(false), case.answer(true)) {
+            (Some(one_by_one), Some(batch)) => {
+                aoc2022::y2022::day05::part1(lines.clone()) == Ok(one_by_one)
+                    && aoc2022::y2022::day05::part2(lines) == Ok(batch)
+            }
+            _ => true,
+        }
+    });
+}
+
+#[test]
+fn day05_drawing_round_trips() {
+    check("day05 drawing", day05_case, day05_shrink, |case| {
+        let stacks: Vec<Vec<String>> = case
+            .stacks
+            .iter()
+            .map(|stack| stack.iter().map(char::to_string).collect())
+            .collect();
+        let drawing = aoc2022::y2022::day05::render_stacks(&stacks, None);
+        aoc2022::y2022::day05::parse_stacks(&drawing) == Ok(stacks)
+    });
+}
+
+// Day 06
+
+fn day06_reference(packet: &str, length: usize) -> Option<usize> {
+    let chars: Vec<char> = packet.chars().collect();
+    (length..=chars.len()).find(|&end| {
+        chars[end - length..end]
+            .iter()
+            .collect::<HashSet<_>>()
+            .len()
+            == length
+    })
+}
+
+#[test]
+fn day06_matches_reference() {
+    check(
+        "day06",
+        |rng: &mut Rng| {
+            let alphabet = rng.range(2, 26) as u8;
+            (0..rng.range(0, 60))
+                .map(|_| (b'a' + rng.below(alphabet as usize) as u8) as char)
+                .collect::<String>()
+        },
+        |packet| {
+            let chars: Vec<char> = packet.chars().collect();
+            shrink_vec(&chars, |&c| {
+                shrink_num(c as u64 - 'a' as u64)
+                    .into_iter()
+                    .map(|n| (b'a' + n as u8) as char)
+                    .collect()
+            })
+            .into_iter()
+            .map(|c| c.into_iter().collect())
+            .collect()
+        },
+        |packet| {
+            let lines = vec![packet.clone()];
+            let part1 = day06_reference(packet, 4)
+                .is_none_or(|pos| aoc2022::y2022::day06::part1(lines.clone()) == Ok(pos));
+            let part2 = day06_reference(packet, 14)
+                .is_none_or(|pos| aoc2022::y2022::day06::part2(lines.clone()) == Ok(pos));
+            part1 && part2
+        },
+    );
+}
+
+#[test]
+fn day06_bytes_match_reference() {
+    check(
+        "day06_bytes",
+        |rng: &mut Rng| {
+            // Small alphabets and long windows make markers rare, so both kinds of cases happen
+            let alphabet = rng.range(1, 256) as usize;
+            let data: Vec<u8> = (0..rng.range(0, 300))
+                .map(|_| rng.below(alphabet) as u8)
+                .collect();
+            (data, rng.range(0, 40) as usize)
+        },
+        |(data, length)| {
+            let mut smaller: Vec<(Vec<u8>, usize)> = shrink_vec(data, |&b| {
+                shrink_num(b as u64).into_iter().map(|n| n as u8).collect()
+            })
+            .into_iter()
+            .map(|data| (data, *length))
+            .collect();
+            smaller.extend(
+                shrink_num(*length as u64)
+                    .into_iter()
+                    .map(|n| (data.clone(), n as usize)),
+            );
+            smaller
+        },
+        |(data, length)| {
+            let reference = (*length..=data.len()).find(|&end| {
+                data[end - length..end].iter().collect::<HashSet<_>>().len() == *length
+            });
+            aoc2022::y2022::day06::find_marker(data, *length) == reference
+        },
+    );
+}
+
+#[test]
+fn day06_scanner_matches_detector() {
+    use aoc2022::y2022::day06::scanner::{Mode, Scanner};
+
+    check(
+        "day06_scanner",
+        |rng: &mut Rng| {
+            let alphabet = rng.range(1, 20) as usize;
+            let data: Vec<u8> = (0..rng.range(0, 100))
+                .map(|_| b'a' + rng.below(alphabet) as u8)
+                .collect();
+            let lengths: Vec<usize> = (0..rng.range(1, 4))
+                .map(|_| rng.range(0, 12) as usize)
+                .collect();
+            (data, lengths)
+        },
+        |(data, lengths)| {
+            shrink_vec(data, |_| Vec::new())
+                .into_iter()
+                .map(|data| (data, lengths.clone()))
+                .collect()
+        },
+        |(data, lengths)| {
+            let find = aoc2022::y2022::day06::find_marker;
+            let scanner = Scanner::new(lengths).with_mode(Mode::Every);
+            let (markers, summary) = scanner.markers(&data[..]).unwrap();
+            let first: Vec<Option<u64>> = lengths
+                .iter()
+                .map(|&length| find(data, length).map(|end| end as u64))
+                .collect();
+            // A marker ends at every position where the suffix so far has one at its end
+            let every = (0..=data.len()).flat_map(|end| {
+                lengths
+                    .iter()
+                    .filter(move |&&length| {
+                        end >= length && find(&data[end - length..end], length) == Some(length)
+                    })
+                    .map(move |&length| (length, end as u64))
+            });
+            let longest = (0..=data.len())
+                .filter_map(|len| find(data, len).map(|_| len as u64))
+                .max()
+                .unwrap_or(0);
+            summary.first == first
+                && markers.iter().map(|m| (m.length, m.end)).eq(every)
+                && summary.longest.len == longest
+        },
+    );
+}
+
+// Day 07
+
+#[derive(Clone, Debug, Default)]
+struct Dir {
+    files: Vec<u64>,
+    dirs: Vec<Dir>,
+}
+
+impl Dir {
+    fn generate(rng: &mut Rng, depth: usize) -> Self {
+        let files = (0..rng.range(0, 3))
+            .map(|_| {
+                if rng.chance(70) {
+                    rng.range(1, 200_000)
+                } else {
+                    rng.range(1_000_000, 8_000_000)
+                }
+            })
+            .collect();
+        let dirs = if depth < 3 {
+            (0..rng.range(0, 3))
+                .map(|_| Dir::generate(rng, depth + 1))
+                .collect()
+        } else {
+            Vec::new()
+        };
+        Dir { files, dirs }
+    }
+
+    fn transcript(&self, lines: &mut Vec<String>) {
+        lines.push(String::from("$ ls"));
+        for i in 0..self.dirs.len() {
+            lines.push(format!("dir d{}", i));
+        }
+        for (i, size) in self.files.iter().enumerate() {
+            lines.push(format!("{} f{}.txt", size, i));
+        }
+        for (i, dir) in self.dirs.iter().enumerate() {
+            lines.push(format!("$ cd d{}", i));
+            dir.transcript(lines);
+            lines.push(String::from("$ cd .."));
+        }
+    }
+
+    fn shrink(&self) -> Vec<Dir> {
+        let mut candidates = Vec::new();
+        for files in shrink_vec(&self.files, |&n| shrink_num(n)) {
+            candidates.push(Dir {
+                files,
+                dirs: self.dirs.clone(),
+            });
+        }
+        for dirs in shrink_vec(&self.dirs, Dir::shrink) {
+            candidates.push(Dir {
+                files: self.files.clone(),
+                dirs,
+            });
+        }
+        candidates
+    }
+}
+
+// Replays the transcript keeping a flat list of (path, size) for every file, then sizes each
+// directory by scanning every file
+fn day07_reference(lines: &[String]) -> Vec<u64> {
+    let mut cwd: Vec<&str> = Vec::new();
+    let mut dirs: Vec<Vec<&str>> = vec![Vec::new()];
+    let mut files: Vec<(Vec<&str>, u64)> = Vec::new();
+    for line in lines {
+        let words: Vec<&str> = line.split(' ').collect();
+        match words[..] {
+            ["$", "cd", "/"] => cwd.clear(),
+            ["$", "cd", ".."] => {
+                cwd.pop();
+            }
+            ["$", "cd", name] => cwd.push(name),
+            ["$", "ls"] => {}
+            ["dir", name] => {
+                let mut path = cwd.clone();
+                path.push(name);
+                dirs.push(path);
+            }
+            [size, _] => files.push((cwd.clone(), size.parse().unwrap())),
+            _ => panic!("Unexpected line {}", line),
+        }
+    }
+
+    let sizes: HashMap<&Vec<&str>, u64> = dirs
+        .iter()
+        .map(|dir| {
+            let size = files
+                .iter()
+                .filter(|(path, _)| path.starts_with(dir))
+                .map(|(_, size)| size)
+                .sum();
+            (dir, size)
+        })
+        .collect();
+    sizes.into_values().collect()
+}
+
+#[test]
+fn day07_matches_reference() {
+    check(
+        "day07",
+        |rng: &mut Rng| Dir::generate(rng, 0),
+        Dir::shrink,
+        |root| {
+            let mut lines = vec![String::from("$ cd /")];
+            root.transcript(&mut lines);
+            let sizes = day07_reference(&lines);
+            let used = sizes.iter().copied().max().unwrap();
+
+            let part1: u64 = sizes.iter().filter(|&&s| s <= 100000).sum();
+            let mut matches = aoc2022::y2022::day07::part1(lines.clone()) == Ok(part1 as usize)
+                && aoc2022::y2022::day07_alt::part1(lines.clone()) == Ok(part1 as usize);
+
+            if used > 40_000_000 && used <= 70_000_000 {
+                let missing = used - 40_000_000;
+                let part2 = sizes
+                    .iter()
+                    .copied()
+                    .filter(|&s| s >= missing)
+                    .min()
+                    .unwrap();
+                matches &= aoc2022::y2022::day07::part2(lines.clone()) == Ok(part2 as usize)
+                    && aoc2022::y2022::day07_alt::part2(lines) == Ok(part2 as usize);
+            }
+            matches
+        },
+    );
+}
+
+// Day 08
+
+fn day08_views(grid: &[Vec<u32>], row: usize, col: usize) -> [(usize, bool); 4] {
+    // For each direction, how many trees can be seen and whether the edge is visible
+    let height = grid[row][col];
+    let mut lines: [Vec<u32>; 4] = Default::default();
+    lines[0] = (0..row).rev().map(|r| grid[r][col]).collect();
+    lines[1] = (row + 1..grid.len()).map(|r| grid[r][col]).collect();
+    lines[2] = (0..col).rev().map(|c| grid[row][c]).collect();
+    lines[3] = (col + 1..grid[0].len()).map(|c| grid[row][c]).collect();
+    lines.map(|trees| match trees.iter().position(|&t| t >= height) {
+        Some(blocker) => (blocker + 1, false),
+        None => (trees.len(), true),
+    })
+}
+
+#[test]
+fn day08_matches_reference() {
+    check(
+        "day08",
+        |rng: &mut Rng| {
+            let (n, m) = (rng.range(2, 8), rng.range(2, 8));
+            let max = rng.range(1, 9) as usize;
+            (0..n)
+                .map(|_| (0..m).map(|_| rng.below(max + 1) as u32).collect())
+                .collect::<Vec<Vec<u32>>>()
+        },
+        |grid| {
+            let mut candidates = Vec::new();
+            if grid.len() > 2 {
+                for r in 0..grid.len() {
+                    let mut smaller = grid.clone();
+                    smaller.remove(r);
+                    candidates.push(smaller);
+                }
+            }
+            if grid[0].len() > 2 {
+                for c in 0..grid[0].len() {
+                    let mut smaller = grid.clone();
+                    smaller.iter_mut().for_each(|row| {
+                        row.remove(c);
+                    });
+                    candidates.push(smaller);
+                }
+            }
+            for r in 0..grid.len() {
+                for c in 0..grid[0].len() {
+                    for h in shrink_num(grid[r][c] as u64) {
+                        let mut smaller = grid.clone();
+                        smaller[r][c] = h as u32;
+                        candidates.push(smaller);
+                    }
+                }
+            }
+            candidates
+        },
+        |grid| {
+            let lines: Vec<String> = grid
+                .iter()
+                .map(|row| row.iter().map(u32::to_string).collect())
+                .collect();
+
+            let mut visible = 0;
+            let mut best = 0;
+            for row in 0..grid.len() {
+                for col in 0..grid[0].len() {
+                    let views = day08_views(grid, row, col);
+                    if views.iter().any(|&(_, edge)| edge) {
+                        visible += 1;
+                    }
+                    best = best.max(views.iter().map(|&(d, _)| d).product::<usize>());
+                }
+            }
+            aoc2022::y2022::day08::part1(lines.clone()) == Ok(visible)
+                && aoc2022::y2022::day08::part2(lines) == Ok(best)
+        },
+    );
+}
+
+// Day 09
+
+fn day09_reference(moves: &[(char, u64)], knots: usize) -> usize {
+    let mut rope = vec![(0i64, 0i64); knots];
+    let mut visited = HashSet::new();
+    visited.insert(rope[knots - 1]);
+    for &(dir, n) in moves {
+        for _ in 0..n {
+            match dir {
+                'U' => rope[0].1 += 1,
+                'D' => rope[0].1 -= 1,
+                'L' => rope[0].0 -= 1,
+                _ => rope[0].0 += 1,
+            }
+            for i in 1..knots {
+                let (dx, dy) = (rope[i - 1].0 - rope[i].0, rope[i - 1].1 - rope[i].1);
+                if dx.abs() > 1 || dy.abs() > 1 {
+                    rope[i].0 += dx.signum();
+                    rope[i].1 += dy.signum();
+                }
+            }
+            visited.insert(rope[knots - 1]);
+        }
+    }
+    visited.len()
+}
+
+#[test]
+fn day09_matches_reference() {
+    check(
+        "day09",
+        |rng: &mut Rng| {
+            (0..rng.range(0, 30))
+                .map(|_| (*rng.pick(&['U', 'D', 'L', 'R']), rng.range(1, 12)))
+                .collect::<Vec<(char, u64)>>()
+        },
+        |moves| {
+            shrink_vec(moves, |&(d, n)| {
+                shrink_num(n)
+                    .into_iter()
+                    .filter(|&n| n > 0)
+                    .map(|n| (d, n))
+                    .collect()
+            })
+        },
+        |moves| {
+            let lines: Vec<String> = moves.iter().map(|(d, n)| format!("{} {}", d, n)).collect();
+            aoc2022::y2022::day09::part1(lines.clone()) == Ok(day09_reference(moves, 2))
+                && aoc2022::y2022::day09::part2(lines) == Ok(day09_reference(moves, 10))
+        },
+    );
+}
@@ -0,0 +1,133 @@
+// Stable counterpart of the fuzz targets in `fuzz/`: every solver must return an answer or an
+// error on arbitrary input, never panic. Inputs that used to crash a solver are kept under
+// `tests/corpus/<year>/<day>/` and replayed here, on top of a batch of randomly generated inputs.
+
+mod common;
+
+use std::fs;
+use std::panic::{self, AssertUnwindSafe};
+
+use common::{check, shrink_vec, Rng};
+
+macro_rules! solve_both {
+    ($day:expr, $lines:expr, [$($name:ident),*]) => {
+        match $day {
+            $(stringify!($name) => {
+                let _ = aoc2022::y2022::$name::part1($lines.clone());
+                let _ = aoc2022::y2022::$name::part2($lines);
+            })*
+            day => panic!("Unknown day {}", day),
+        }
+    };
+}
+
+fn solve(day: &str, data: &[u8]) {
+    let lines = aoc2022::lines_from_bytes(data);
+    solve_both!(
+        day,
+        lines,
+        [day01, day02, day03, day04, day05, day06, day07, day07_alt, day08, day09]
+    );
+}
+
+#[test]
+fn corpus_does_not_panic() {
+    let mut crashes = Vec::new();
+    for day_dir in fs::read_dir("tests/corpus/2022").unwrap() {
+        let day_dir = day_dir.unwrap().path();
+        let day = day_dir.file_name().unwrap().to_str().unwrap().to_owned();
+        for case in fs::read_dir(&day_dir).unwrap() {
+            let case = case.unwrap().path();
+            let data = fs::read(&case).unwrap();
+            if panic::catch_unwind(AssertUnwindSafe(|| solve(&day, &data))).is_err() {
+                crashes.push(case.display().to_string());
+            }
+        }
+    }
+    assert!(crashes.is_empty(), "Solvers panicked on {:?}", crashes);
+}
+
+// Fragments that are likely to get parsers into interesting states. Numbers are only sometimes
+// followed by a separator, so that they can also merge into huge values.
+const NUMBERS: &[&str] = &["0", "1", "3", "12", "-1", "99999", "18446744073709551616"];
+const SEPARATORS: &[&str] = &[" ", "\n", ",", "-"];
+const FRAGMENTS: &[&str] = &[
+    "\n", "\n\n", " ", "    ", "A", "B", "C", "X", "Y", "Z", "a", "b", "z", "é", "\u{0}", "[", "]",
+    "[A]", "[B] ", "move ", " from ", " to ", "$ cd ", "$ cd /", "$ cd ..", "$ ls", "dir ", "U ",
+    "D ", "L ", "R ",
+];
+
+fn random_input(rng: &mut Rng) -> Vec<u8> {
+    let mut data = Vec::new();
+    for _ in 0..rng.range(0, 40) {
+        match rng.range(0, 9) {
+            0 => data.push(rng.next_u64() as u8),
+            1 | 2 => {
+                data.extend(rng.pick(NUMBERS).bytes());
+                if rng.chance(50) {
+                    data.extend(rng.pick(SEPARATORS).bytes());
+                }
+            }
+            _ => data.extend(rng.pick(FRAGMENTS).bytes()),
+        }
+    }
+    data
+}
+
+fn random_input_does_not_panic(day: &str) {
+    check(
+        day,
+        random_input,
+        |data| shrink_vec(data, |_| Vec::new()),
+        |data| {
+            solve(day, data);
+            true
+        },
+    );
+}
+
+#[test]
+fn day01_random_input_does_not_panic() {
+    random_input_does_not_panic("day01");
+}
+
+#[test]
+fn day02_random_input_does_not_panic() {
+    random_input_does_not_panic("day02");
+}
+
+#[test]
+fn day03_random_input_does_not_panic() {
+    random_input_does_not_panic("day03");
+}
+
+#[test]
+fn day04_random_input_does_not_panic() {
+    random_input_does_not_panic("day04");
+}
+
+#[test]
+fn day05_random_input_does_not_panic() {
+    random_input_does_not_panic("day05");
+}
+
+#[test]
+fn day06_random_input_does_not_panic() {
+    random_input_does_not_panic("day06");
+}
+
+#[test]
+fn day07_random_input_does_not_panic() {
+    random_input_does_not_panic("day07");
+    random_input_does_not_panic("day07_alt");
+}
+
+#[test]
+fn day08_random_input_does_not_panic() {
+    random_input_does_not_panic("day08");
+}
+
+#[test]
+fn day09_random_input_does_not_panic() {
+    random_input_does_not_panic("day09");
+}